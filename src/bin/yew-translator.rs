@@ -0,0 +1,184 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use yew_translator::{
+    add_keys, check_catalogs, check_placeholders, extract_keys_from_dir, load_locales, unused_keys,
+};
+
+/// Tools for the catalogs of yew_translator.
+#[derive(Parser)]
+#[command(name = "yew-translator", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Check the catalogs of a locales directory against the source language.
+    Check(CheckArgs),
+    /// Extract the keys used by the Rust sources, listing the new and unused ones of the source language.
+    Extract(ExtractArgs),
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    /// The locales directory, holding `<language>.json` and `<namespace>/<language>.json` catalogs.
+    #[arg(default_value = "locales")]
+    locales: PathBuf,
+    /// The language the other ones are checked against.
+    #[arg(long, default_value = "en")]
+    source: String,
+    /// Also compare the placeholders of every template with the ones of the source language.
+    #[arg(long)]
+    placeholders: bool,
+    /// How the problems are written.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Human)]
+    format: OutputFormat,
+}
+
+#[derive(Args)]
+struct ExtractArgs {
+    /// The directory of the Rust sources.
+    #[arg(default_value = "src")]
+    sources: PathBuf,
+    /// The locales directory, holding `<language>.json` and `<namespace>/<language>.json` catalogs.
+    #[arg(long, default_value = "locales")]
+    locales: PathBuf,
+    /// The language whose catalogs are compared to the sources.
+    #[arg(long, default_value = "en")]
+    source: String,
+    /// Add the new keys to the catalogs of the source language, with an empty translation.
+    #[arg(long)]
+    write: bool,
+    /// How the keys are written.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Human)]
+    format: OutputFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    /// One problem per line.
+    Human,
+    /// A JSON array of problems.
+    Json,
+}
+
+fn check(args: CheckArgs) -> Result<bool, String> {
+    let catalogs = load_locales(&args.locales).map_err(|err| err.to_string())?;
+    if !catalogs.contains_key(&args.source) {
+        return Err(format!(
+            "No catalog of the source language `{}` in `{}`",
+            args.source,
+            args.locales.display()
+        ));
+    }
+    let mut issues = check_catalogs(&catalogs, &args.source);
+    if args.placeholders {
+        issues.extend(check_placeholders(&catalogs, &args.source));
+        issues.sort_by(|a, b| (&a.language, &a.key).cmp(&(&b.language, &b.key)));
+    }
+    match args.format {
+        OutputFormat::Human => {
+            for issue in &issues {
+                println!("{}", issue);
+            }
+            match issues.len() {
+                0 => println!("No problem found in {} languages.", catalogs.len()),
+                count => println!("{} problem(s) found.", count),
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&issues).map_err(|err| err.to_string())?
+        ),
+    }
+    Ok(issues.is_empty())
+}
+
+fn extract(args: ExtractArgs) -> Result<bool, String> {
+    let used = extract_keys_from_dir(&args.sources).map_err(|err| err.to_string())?;
+    let catalogs = if args.locales.exists() {
+        load_locales(&args.locales).map_err(|err| err.to_string())?
+    } else {
+        HashMap::new()
+    };
+    let catalog = catalogs.get(&args.source).cloned().unwrap_or_default();
+    let new: BTreeSet<&String> = used
+        .iter()
+        .filter(|key| !catalog.contains_key(*key))
+        .collect();
+    let unused = unused_keys(&catalog, &used);
+    match args.format {
+        OutputFormat::Human => {
+            println!("{} keys used.", used.len());
+            for key in &new {
+                println!("new: `{}`", key);
+            }
+            for key in &unused {
+                println!("unused: `{}`", key);
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "used": used,
+                "new": new,
+                "unused": unused,
+            }))
+            .map_err(|err| err.to_string())?
+        ),
+    }
+    if args.write {
+        write_keys(&args.locales, &args.source, new)?;
+    }
+    Ok(true)
+}
+
+/// Add the keys to the catalogs of their namespace, creating them if needed.
+fn write_keys(locales: &Path, language: &str, keys: BTreeSet<&String>) -> Result<(), String> {
+    let mut namespaces: BTreeMap<Option<&str>, Vec<&str>> = BTreeMap::new();
+    for key in keys {
+        let (namespace, key) = match key.split_once(':') {
+            Some((namespace, key)) => (Some(namespace), key),
+            None => (None, key.as_str()),
+        };
+        namespaces.entry(namespace).or_default().push(key);
+    }
+    for (namespace, keys) in namespaces {
+        let mut path = locales.to_path_buf();
+        if let Some(namespace) = namespace {
+            path.push(namespace);
+        }
+        std::fs::create_dir_all(&path).map_err(|err| err.to_string())?;
+        path.push(format!("{}.json", language));
+        let mut catalog = match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|err| format!("{}: {}", path.display(), err))?,
+            Err(_) => serde_json::Value::Null,
+        };
+        for conflict in add_keys(&mut catalog, keys) {
+            eprintln!("conflict: `{}` in `{}`", conflict, path.display());
+        }
+        let content = serde_json::to_string_pretty(&catalog).map_err(|err| err.to_string())?;
+        std::fs::write(&path, content + "\n").map_err(|err| err.to_string())?;
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let result = match Cli::parse().command {
+        Command::Check(args) => check(args),
+        Command::Extract(args) => extract(args),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,393 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::path::Path;
+
+use serde::Serialize;
+
+use super::CodegenError;
+use crate::templater::{encode_json_to_hashmap, placeholders, Placeholder, PlaceholderKind};
+
+/// The namespace of the catalogs stored directly in the locales directory.
+const DEFAULT_NAMESPACE: &str = "common";
+
+/// The flattened catalogs of every language, the keys of the namespaces other than `common`
+/// being prefixed by `namespace:`.
+pub type Catalogs = HashMap<String, HashMap<String, String>>;
+
+/// A problem found in the catalogs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CatalogIssueKind {
+    /// The key of the source language is missing in the language.
+    MissingKey,
+    /// The key doesn't exist in the source language.
+    ExtraKey,
+    /// The template can't be parsed.
+    InvalidTemplate { error: String },
+    /// The template includes a key that doesn't exist.
+    MissingInclude { include: String },
+    /// The template has a placeholder absent from the source language.
+    PlaceholderAdded { placeholder: String },
+    /// The template lacks a placeholder of the source language.
+    PlaceholderMissing { placeholder: String },
+    /// The template uses a data field or key of the source language with other kinds of placeholders.
+    PlaceholderChanged { expected: String, found: String },
+}
+
+/// A problem found in the catalog of a language.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CatalogIssue {
+    /// The language of the catalog.
+    pub language: String,
+    /// The key concerned.
+    pub key: String,
+    /// The problem.
+    #[serde(flatten)]
+    pub kind: CatalogIssueKind,
+}
+
+impl fmt::Display for CatalogIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "['{}'] `{}`: ", self.language, self.key)?;
+        match &self.kind {
+            CatalogIssueKind::MissingKey => write!(f, "missing key"),
+            CatalogIssueKind::ExtraKey => write!(f, "key absent from the source language"),
+            CatalogIssueKind::InvalidTemplate { error } => write!(f, "invalid template: {}", error),
+            CatalogIssueKind::MissingInclude { include } => {
+                write!(f, "includes the missing key `{}`", include)
+            }
+            CatalogIssueKind::PlaceholderAdded { placeholder } => {
+                write!(
+                    f,
+                    "placeholder `{}` absent from the source language",
+                    placeholder
+                )
+            }
+            CatalogIssueKind::PlaceholderMissing { placeholder } => {
+                write!(f, "missing placeholder `{}`", placeholder)
+            }
+            CatalogIssueKind::PlaceholderChanged { expected, found } => {
+                write!(f, "placeholder `{}` instead of `{}`", found, expected)
+            }
+        }
+    }
+}
+
+/// Load the catalogs of a locales directory, `<locales>/<language>.json` being the `common` namespace
+/// and `<locales>/<namespace>/<language>.json` the other ones.
+pub fn load_locales(locales: impl AsRef<Path>) -> Result<Catalogs, CodegenError> {
+    let mut catalogs = Catalogs::new();
+    load_namespace(locales.as_ref(), None, &mut catalogs)?;
+    Ok(catalogs)
+}
+
+fn load_namespace(
+    dir: &Path,
+    namespace: Option<&str>,
+    catalogs: &mut Catalogs,
+) -> Result<(), CodegenError> {
+    let io_error = |err: std::io::Error| CodegenError::Io(format!("{}: {}", dir.display(), err));
+    for entry in std::fs::read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        let Some(name) = path.file_stem().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if path.is_dir() {
+            if namespace.is_none() {
+                load_namespace(&path, Some(name), catalogs)?;
+            }
+            continue;
+        }
+        if path.extension().is_none_or(|extension| extension != "json") {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)
+            .map_err(|err| CodegenError::Io(format!("{}: {}", path.display(), err)))?;
+        let json: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|err| CodegenError::Parse(format!("{}: {}", path.display(), err)))?;
+        let catalog = catalogs.entry(name.to_string()).or_default();
+        for (key, template) in encode_json_to_hashmap(&json) {
+            let key = match namespace {
+                Some(namespace) => format!("{}:{}", namespace, key),
+                None => key,
+            };
+            catalog.insert(key, template);
+        }
+    }
+    Ok(())
+}
+
+/// Check the catalogs against the ones of the source language, reporting the missing and extra keys,
+/// the invalid templates and the `{{{…}}}` includes of missing keys, sorted by language and key.
+pub fn check_catalogs(catalogs: &Catalogs, source_language: &str) -> Vec<CatalogIssue> {
+    let empty = HashMap::new();
+    let source = catalogs.get(source_language).unwrap_or(&empty);
+    let sorted: BTreeMap<&String, &HashMap<String, String>> = catalogs.iter().collect();
+    let mut issues = Vec::new();
+    for (language, catalog) in sorted {
+        let mut issue = |key: &str, kind| {
+            issues.push(CatalogIssue {
+                language: language.clone(),
+                key: key.to_string(),
+                kind,
+            })
+        };
+        let keys: BTreeMap<&String, Option<&String>> = source
+            .keys()
+            .chain(catalog.keys())
+            .map(|key| (key, catalog.get(key)))
+            .collect();
+        for (key, template) in keys {
+            let Some(template) = template else {
+                issue(key, CatalogIssueKind::MissingKey);
+                continue;
+            };
+            if !source.contains_key(key) {
+                issue(key, CatalogIssueKind::ExtraKey);
+            }
+            let placeholders = match placeholders(template) {
+                Ok(placeholders) => placeholders,
+                Err(err) => {
+                    issue(
+                        key,
+                        CatalogIssueKind::InvalidTemplate {
+                            error: err.to_string(),
+                        },
+                    );
+                    continue;
+                }
+            };
+            for placeholder in placeholders {
+                if placeholder.kind == PlaceholderKind::Include
+                    && resolve_include(catalog, key, &placeholder.key).is_none()
+                {
+                    issue(
+                        key,
+                        CatalogIssueKind::MissingInclude {
+                            include: placeholder.key,
+                        },
+                    );
+                }
+            }
+        }
+    }
+    issues
+}
+
+/// Compare the placeholders of every template with the ones of the source language, reporting the
+/// placeholders added, omitted or whose kind changed (such as `{{name}}` becoming `{{*name}}`),
+/// sorted by language and key. The keys missing in a language and the invalid templates are left to
+/// `check_catalogs`.
+pub fn check_placeholders(catalogs: &Catalogs, source_language: &str) -> Vec<CatalogIssue> {
+    let Some(source) = catalogs.get(source_language) else {
+        return Vec::new();
+    };
+    let sorted: BTreeMap<&String, &HashMap<String, String>> = catalogs
+        .iter()
+        .filter(|(language, _)| *language != source_language)
+        .collect();
+    let mut issues = Vec::new();
+    for (language, catalog) in sorted {
+        let sorted: BTreeMap<&String, &String> = catalog.iter().collect();
+        for (key, template) in sorted {
+            let Some(expected) = source
+                .get(key)
+                .and_then(|template| placeholder_kinds(template))
+            else {
+                continue;
+            };
+            let Some(found) = placeholder_kinds(template) else {
+                continue;
+            };
+            let mut issue = |kind| {
+                issues.push(CatalogIssue {
+                    language: language.clone(),
+                    key: key.clone(),
+                    kind,
+                })
+            };
+            let names: BTreeSet<&String> = expected.keys().chain(found.keys()).collect();
+            for name in names {
+                let written = |kinds: Option<&BTreeSet<PlaceholderKind>>| -> Vec<String> {
+                    kinds
+                        .into_iter()
+                        .flatten()
+                        .map(|kind| {
+                            Placeholder {
+                                kind: *kind,
+                                key: name.clone(),
+                            }
+                            .to_string()
+                        })
+                        .collect()
+                };
+                match (expected.get(name), found.get(name)) {
+                    (Some(expected), Some(found)) if expected != found => {
+                        issue(CatalogIssueKind::PlaceholderChanged {
+                            expected: written(Some(expected)).join(", "),
+                            found: written(Some(found)).join(", "),
+                        })
+                    }
+                    (Some(expected), None) => {
+                        for placeholder in written(Some(expected)) {
+                            issue(CatalogIssueKind::PlaceholderMissing { placeholder });
+                        }
+                    }
+                    (None, Some(found)) => {
+                        for placeholder in written(Some(found)) {
+                            issue(CatalogIssueKind::PlaceholderAdded { placeholder });
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    issues
+}
+
+/// The kinds of placeholders of each data field or key of a template, if it is valid.
+fn placeholder_kinds(template: &str) -> Option<BTreeMap<String, BTreeSet<PlaceholderKind>>> {
+    let mut kinds: BTreeMap<String, BTreeSet<PlaceholderKind>> = BTreeMap::new();
+    for placeholder in placeholders(template).ok()? {
+        kinds
+            .entry(placeholder.key)
+            .or_default()
+            .insert(placeholder.kind);
+    }
+    Some(kinds)
+}
+
+/// Find the key included by a template the way the provider does: in the namespace of the template first,
+/// then in `common`.
+pub(super) fn resolve_include(
+    catalog: &HashMap<String, String>,
+    key: &str,
+    include: &str,
+) -> Option<String> {
+    let candidates = match include.split_once(':') {
+        Some((DEFAULT_NAMESPACE, include)) => vec![include.to_string()],
+        Some(_) => vec![include.to_string()],
+        None => key
+            .split_once(':')
+            .map(|(namespace, _)| format!("{}:{}", namespace, include))
+            .into_iter()
+            .chain([include.to_string()])
+            .collect(),
+    };
+    candidates
+        .into_iter()
+        .find(|candidate| catalog.contains_key(candidate))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::{check_catalogs, check_placeholders, CatalogIssueKind};
+
+    #[test]
+    fn test_check_catalogs() {
+        let catalog = |entries: &[(&str, &str)]| {
+            entries
+                .iter()
+                .map(|(key, template)| (key.to_string(), template.to_string()))
+                .collect::<HashMap<_, _>>()
+        };
+        let catalogs = HashMap::from([
+            (
+                "en".to_string(),
+                catalog(&[
+                    ("hello", "Hello {{name}}."),
+                    ("menu.home", "Home"),
+                    ("checkout:title", "{{{hello}}} {{{total}}}"),
+                    ("checkout:total", "Total"),
+                ]),
+            ),
+            (
+                "fr".to_string(),
+                catalog(&[
+                    ("hello", "Bonjour {{name."),
+                    ("checkout:title", "{{{hello}}} {{{menu.contact}}}"),
+                    ("checkout:total", "Total"),
+                    ("menu.about", "À propos"),
+                ]),
+            ),
+        ]);
+        let issues: Vec<_> = check_catalogs(&catalogs, "en")
+            .into_iter()
+            .map(|issue| (issue.language, issue.key, issue.kind))
+            .collect();
+        let fr = |key: &str, kind| ("fr".to_string(), key.to_string(), kind);
+        assert_eq!(issues.len(), 4);
+        assert_eq!(
+            issues[0],
+            fr(
+                "checkout:title",
+                CatalogIssueKind::MissingInclude {
+                    include: "menu.contact".to_string()
+                }
+            )
+        );
+        assert!(matches!(
+            issues[1].2,
+            CatalogIssueKind::InvalidTemplate { .. }
+        ));
+        assert_eq!(issues[2], fr("menu.about", CatalogIssueKind::ExtraKey));
+        assert_eq!(issues[3], fr("menu.home", CatalogIssueKind::MissingKey));
+    }
+
+    #[test]
+    fn test_check_placeholders() {
+        let catalogs = HashMap::from([
+            (
+                "en".to_string(),
+                HashMap::from([
+                    (
+                        "hello".to_string(),
+                        "Hello {{name}}, {{{sign}}}".to_string(),
+                    ),
+                    ("item".to_string(), "{{count}} {{*unit}}".to_string()),
+                ]),
+            ),
+            (
+                "fr".to_string(),
+                HashMap::from([
+                    (
+                        "hello".to_string(),
+                        "Bonjour {{{sign}}} {{title}}".to_string(),
+                    ),
+                    ("item".to_string(), "{{count}} {{unit}}".to_string()),
+                ]),
+            ),
+        ]);
+        let issues: Vec<_> = check_placeholders(&catalogs, "en")
+            .into_iter()
+            .map(|issue| (issue.key, issue.kind))
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                (
+                    "hello".to_string(),
+                    CatalogIssueKind::PlaceholderMissing {
+                        placeholder: "{{name}}".to_string()
+                    }
+                ),
+                (
+                    "hello".to_string(),
+                    CatalogIssueKind::PlaceholderAdded {
+                        placeholder: "{{title}}".to_string()
+                    }
+                ),
+                (
+                    "item".to_string(),
+                    CatalogIssueKind::PlaceholderChanged {
+                        expected: "{{*unit}}".to_string(),
+                        found: "{{unit}}".to_string()
+                    }
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use crate::templater::StringTemplaterError;
+
+/// Any errors that might occurs while generating the key accessors.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CodegenError {
+    #[error("IO error: `{0}`")]
+    Io(String),
+    #[error("Parse error: `{0}`")]
+    Parse(String),
+    #[error("Invalid template for `{key}`: {error}")]
+    Template {
+        key: String,
+        error: StringTemplaterError,
+    },
+    #[error("Duplicate name: `{0}`")]
+    DuplicateName(String),
+}
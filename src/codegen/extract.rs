@@ -0,0 +1,395 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use super::check::resolve_include;
+use super::CodegenError;
+use crate::templater::{placeholders, PlaceholderKind};
+
+/// The translation methods of `I18nHandler` (and the macros), with the position of their key argument.
+const TRANSLATION_METHODS: [(&str, usize); 11] = [
+    ("t", 0),
+    ("tt", 0),
+    ("tth", 0),
+    ("tth_with_options", 0),
+    ("tt_with_options", 0),
+    ("thb", 0),
+    ("thb_registry", 1),
+    ("try_t", 0),
+    ("try_tt", 0),
+    ("try_tth", 0),
+    ("try_thb", 0),
+];
+
+/// A token of a Rust source, as far as the extraction is concerned.
+#[derive(Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Punct(char),
+}
+
+/// Extract the keys given as string literals to the translation methods (`t("…")`, `tt("…", …)`, `tth`,
+/// `thb`, `thb_registry` and their `try_` counterparts), to the `t!` and `tt!` macros and to the `i18n_key`
+/// prop of `<Trans>` from a Rust source.
+///
+/// The keys built at runtime and the keys relative to a scoped handler can't be found, and the keys given
+/// to a namespaced handler (`use_translation_ns`, `namespaced`) are found without their `namespace:` prefix.
+pub fn extract_keys(source: &str) -> BTreeSet<String> {
+    let tokens = tokenize(source);
+    let mut keys = BTreeSet::new();
+    for (index, token) in tokens.iter().enumerate() {
+        let Token::Ident(name) = token else {
+            continue;
+        };
+        let previous = index.checked_sub(1).map(|index| &tokens[index]);
+        // The macros take the handler as their first argument.
+        let (arguments, skipped) = match (previous, tokens.get(index + 1)) {
+            (Some(Token::Punct('.')), Some(Token::Punct('('))) => (index + 2, 0),
+            (_, Some(Token::Punct('!'))) if tokens.get(index + 2) == Some(&Token::Punct('(')) => {
+                (index + 3, 1)
+            }
+            (_, Some(Token::Punct('='))) if name == "i18n_key" => {
+                if let Some(Token::Str(key)) = tokens.get(index + 2) {
+                    keys.insert(normalize_key(key));
+                }
+                continue;
+            }
+            _ => continue,
+        };
+        let Some((_, position)) = TRANSLATION_METHODS
+            .iter()
+            .find(|(method, _)| method == name)
+        else {
+            continue;
+        };
+        if let Some(key) = argument(&tokens[arguments..], position + skipped) {
+            keys.insert(normalize_key(key));
+        }
+    }
+    keys
+}
+
+/// Extract the keys used by every `.rs` file of a directory and its subdirectories, as `extract_keys`
+/// does, skipping the hidden directories and the `target` ones.
+pub fn extract_keys_from_dir(dir: impl AsRef<Path>) -> Result<BTreeSet<String>, CodegenError> {
+    let mut keys = BTreeSet::new();
+    extract_dir(dir.as_ref(), &mut keys)?;
+    Ok(keys)
+}
+
+fn extract_dir(dir: &Path, keys: &mut BTreeSet<String>) -> Result<(), CodegenError> {
+    let io_error = |err: std::io::Error| CodegenError::Io(format!("{}: {}", dir.display(), err));
+    for entry in std::fs::read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        if path.is_dir() {
+            if !name.starts_with('.') && name != "target" {
+                extract_dir(&path, keys)?;
+            }
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            let source = std::fs::read_to_string(&path)
+                .map_err(|err| CodegenError::Io(format!("{}: {}", path.display(), err)))?;
+            keys.extend(extract_keys(&source));
+        }
+    }
+    Ok(())
+}
+
+/// List the keys of a catalog that are neither used nor included by a used key through `{{{…}}}`.
+pub fn unused_keys(catalog: &HashMap<String, String>, used: &BTreeSet<String>) -> BTreeSet<String> {
+    let mut reachable = BTreeSet::new();
+    let mut pending: Vec<String> = used.iter().cloned().collect();
+    while let Some(key) = pending.pop() {
+        let Some(template) = catalog.get(&key) else {
+            continue;
+        };
+        if !reachable.insert(key.clone()) {
+            continue;
+        }
+        for placeholder in placeholders(template).unwrap_or_default() {
+            if placeholder.kind != PlaceholderKind::Include {
+                continue;
+            }
+            if let Some(included) = resolve_include(catalog, &key, &placeholder.key) {
+                pending.push(included);
+            }
+        }
+    }
+    catalog
+        .keys()
+        .filter(|key| !reachable.contains(*key))
+        .cloned()
+        .collect()
+}
+
+/// Add the keys to a JSON catalog with an empty translation, returning the ones conflicting with an existing entry.
+pub fn add_keys<'a>(catalog: &mut Value, keys: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut conflicts = Vec::new();
+    for key in keys {
+        let path: Vec<&str> = key.split('.').collect();
+        if !insert_empty(catalog, &path) {
+            conflicts.push(key.to_string());
+        }
+    }
+    conflicts
+}
+
+fn insert_empty(value: &mut Value, path: &[&str]) -> bool {
+    if value.is_null() {
+        *value = Value::Object(Map::new());
+    }
+    let (Some(object), Some((name, path))) = (value.as_object_mut(), path.split_first()) else {
+        return false;
+    };
+    if path.is_empty() {
+        if object.contains_key(*name) {
+            return false;
+        }
+        object.insert(name.to_string(), Value::String(String::new()));
+        return true;
+    }
+    insert_empty(object.entry(*name).or_insert(Value::Null), path)
+}
+
+/// Strip the markers that don't belong to the key itself: `~` and the `common:` namespace.
+fn normalize_key(key: &str) -> String {
+    let key = key.strip_prefix('~').unwrap_or(key);
+    key.strip_prefix("common:").unwrap_or(key).to_string()
+}
+
+/// The argument at a position, if it is a lone string literal.
+fn argument(tokens: &[Token], position: usize) -> Option<&String> {
+    let mut depth = 0;
+    let mut current = 0;
+    let mut start = 0;
+    for (index, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Punct('(' | '[' | '{') => depth += 1,
+            Token::Punct(')' | ']' | '}') if depth == 0 => {
+                return (current == position).then(|| lone_string(&tokens[start..index]))?
+            }
+            Token::Punct(')' | ']' | '}') => depth -= 1,
+            Token::Punct(',') if depth == 0 => {
+                if current == position {
+                    return lone_string(&tokens[start..index]);
+                }
+                current += 1;
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn lone_string(tokens: &[Token]) -> Option<&String> {
+    match tokens {
+        [Token::Str(value)] | [Token::Punct('&'), Token::Str(value)] => Some(value),
+        _ => None,
+    }
+}
+
+/// Split a Rust source into identifiers, string literals and punctuation, skipping the comments,
+/// the character literals and the lifetimes.
+fn tokenize(source: &str) -> Vec<Token> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        match c {
+            '/' if chars.get(index + 1) == Some(&'/') => {
+                while index < chars.len() && chars[index] != '\n' {
+                    index += 1;
+                }
+            }
+            '/' if chars.get(index + 1) == Some(&'*') => {
+                let mut depth = 0;
+                while index < chars.len() {
+                    if chars[index] == '/' && chars.get(index + 1) == Some(&'*') {
+                        depth += 1;
+                        index += 2;
+                    } else if chars[index] == '*' && chars.get(index + 1) == Some(&'/') {
+                        depth -= 1;
+                        index += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        index += 1;
+                    }
+                }
+            }
+            '"' => {
+                let (value, end) = read_string(&chars, index + 1);
+                tokens.push(Token::Str(value));
+                index = end;
+            }
+            '\'' => {
+                if chars.get(index + 1) == Some(&'\\') {
+                    index += 2;
+                    while index < chars.len() && chars[index] != '\'' {
+                        index += 1;
+                    }
+                    index += 1;
+                } else if chars.get(index + 2) == Some(&'\'') {
+                    index += 3;
+                } else {
+                    // A lifetime, its name being read as an identifier.
+                    index += 1;
+                }
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let start = index;
+                while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_')
+                {
+                    index += 1;
+                }
+                let ident: String = chars[start..index].iter().collect();
+                let raw = matches!(ident.as_str(), "r" | "br" | "cr")
+                    && matches!(chars.get(index), Some('"' | '#'));
+                let prefixed =
+                    matches!(ident.as_str(), "b" | "c") && chars.get(index) == Some(&'"');
+                if raw {
+                    let hashes = chars[index..].iter().take_while(|c| **c == '#').count();
+                    if chars.get(index + hashes) != Some(&'"') {
+                        // A raw identifier such as `r#type`.
+                        tokens.push(Token::Ident(ident));
+                        continue;
+                    }
+                    let start = index + hashes + 1;
+                    let mut end = start;
+                    while end < chars.len()
+                        && !(chars[end] == '"'
+                            && chars[end + 1..]
+                                .iter()
+                                .take(hashes)
+                                .filter(|c| **c == '#')
+                                .count()
+                                == hashes)
+                    {
+                        end += 1;
+                    }
+                    tokens.push(Token::Str(
+                        chars[start..end.min(chars.len())].iter().collect(),
+                    ));
+                    index = end + 1 + hashes;
+                } else if prefixed {
+                    let (value, end) = read_string(&chars, index + 1);
+                    tokens.push(Token::Str(value));
+                    index = end;
+                } else {
+                    tokens.push(Token::Ident(ident));
+                }
+            }
+            c if c.is_whitespace() => index += 1,
+            c => {
+                tokens.push(Token::Punct(c));
+                index += 1;
+            }
+        }
+    }
+    tokens
+}
+
+/// Read a string literal starting after its opening quote, returning its value and the index following it.
+fn read_string(chars: &[char], mut index: usize) -> (String, usize) {
+    let mut value = String::new();
+    while index < chars.len() {
+        match chars[index] {
+            '"' => return (value, index + 1),
+            '\\' => {
+                index += 1;
+                match chars.get(index) {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some('r') => value.push('\r'),
+                    Some('0') => value.push('\0'),
+                    Some('\n') => {
+                        while chars.get(index + 1).is_some_and(|c| c.is_whitespace()) {
+                            index += 1;
+                        }
+                    }
+                    Some(c) => value.push(*c),
+                    None => {}
+                }
+                index += 1;
+            }
+            c => {
+                value.push(c);
+                index += 1;
+            }
+        }
+    }
+    (value, index)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeSet, HashMap};
+
+    use serde_json::json;
+
+    use super::{add_keys, extract_keys, unused_keys};
+
+    #[test]
+    fn test_extract_keys() {
+        let source = r##"
+            // i18n.t("commented")
+            fn view<'a>(i18n: &'a I18nHandler) -> Html {
+                let quote = '"';
+                let title = i18n.t("menu.home");
+                let hello = i18n.tt("hello", &json!({ "name": "John" }));
+                let scoped = i18n.try_tth(&"checkout:total", &data);
+                let logo = i18n.thb_registry(&registry, "~logo", &data);
+                let card = i18n.try_thb("card", &data);
+                let dynamic = i18n.t(&format!("menu.{}", page));
+                let raw = t!(i18n, r#"common:raw"#);
+                /* i18n.t("also.commented") */
+                html! { <Trans i18n_key="terms" /> }
+            }
+        "##;
+        assert_eq!(
+            extract_keys(source),
+            BTreeSet::from(
+                [
+                    "menu.home",
+                    "hello",
+                    "checkout:total",
+                    "logo",
+                    "card",
+                    "raw",
+                    "terms"
+                ]
+                .map(String::from)
+            )
+        );
+    }
+
+    #[test]
+    fn test_unused_and_added_keys() {
+        let catalog = HashMap::from([
+            ("hello".to_string(), "Hello {{{name}}}".to_string()),
+            ("name".to_string(), "John".to_string()),
+            ("unused".to_string(), "Unused".to_string()),
+        ]);
+        let used = BTreeSet::from(["hello".to_string(), "menu.home".to_string()]);
+        assert_eq!(
+            unused_keys(&catalog, &used),
+            BTreeSet::from(["unused".to_string()])
+        );
+
+        let mut json = json!({ "hello": "Hello", "menu": { "about": "About" } });
+        let conflicts = add_keys(&mut json, ["menu.home", "hello.world"]);
+        assert_eq!(conflicts, vec!["hello.world".to_string()]);
+        assert_eq!(
+            json,
+            json!({ "hello": "Hello", "menu": { "about": "About", "home": "" } })
+        );
+    }
+}
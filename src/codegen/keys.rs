@@ -0,0 +1,176 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+use std::path::Path;
+
+use super::CodegenError;
+use crate::templater::{encode_json_to_hashmap, placeholders, required_fields};
+
+const KEYWORDS: [&str; 51] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Turn a key or a data field into a snake case identifier.
+fn snake_case(name: &str) -> String {
+    let mut ident = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            ident.push(c.to_ascii_lowercase());
+        } else if !ident.is_empty() && !ident.ends_with('_') {
+            ident.push('_');
+        }
+    }
+    let mut ident = ident.trim_end_matches('_').to_string();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    if KEYWORDS.contains(&ident.as_str()) {
+        ident.push('_');
+    }
+    ident
+}
+
+/// Turn a snake case identifier into a pascal case one.
+fn pascal_case(ident: &str) -> String {
+    let mut pascal = String::new();
+    for part in ident.split('_').filter(|part| !part.is_empty()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            pascal.push(first.to_ascii_uppercase());
+            pascal.push_str(chars.as_str());
+        }
+    }
+    if pascal.starts_with(|c: char| c.is_ascii_digit()) {
+        pascal.insert(0, 'K');
+    }
+    pascal
+}
+
+/// Generate the source of a module with one accessor per key of the translations, and a typed
+/// argument struct for every template requiring data.
+pub fn generate_keys_module(
+    translations: &HashMap<String, String>,
+) -> Result<String, CodegenError> {
+    let sorted: BTreeMap<&String, &String> = translations.iter().collect();
+    let mut names = HashSet::new();
+    let mut source = String::new();
+    for (key, template) in sorted {
+        let template_error = |error| CodegenError::Template {
+            key: key.clone(),
+            error,
+        };
+        let name = snake_case(key);
+        if !names.insert(name.clone()) {
+            return Err(CodegenError::DuplicateName(name));
+        }
+        let has_placeholders = !placeholders(template).map_err(template_error)?.is_empty();
+        let fields = required_fields(translations, key).map_err(template_error)?;
+        let _ = writeln!(source, "#[doc = {:?}]", format!("`{}`: {}", key, template));
+
+        if !has_placeholders {
+            let _ = writeln!(
+                source,
+                "pub fn {}(handler: &::yew_translator::I18nHandler) -> ::std::string::String {{\n    handler.t({:?})\n}}\n",
+                name, key
+            );
+            continue;
+        }
+        if fields.is_empty() {
+            let _ = writeln!(
+                source,
+                "pub fn {}(handler: &::yew_translator::I18nHandler) -> ::std::string::String {{\n    handler.tth({:?}, &::std::collections::HashMap::new())\n}}\n",
+                name, key
+            );
+            continue;
+        }
+
+        let args = format!("{}Args", pascal_case(&name));
+        if !names.insert(args.clone()) {
+            return Err(CodegenError::DuplicateName(args));
+        }
+        let mut field_names = HashSet::new();
+        let mut struct_fields = String::new();
+        let mut entries = String::new();
+        for field in &fields {
+            let field_name = snake_case(field);
+            if !field_names.insert(field_name.clone()) {
+                return Err(CodegenError::DuplicateName(format!(
+                    "{}.{}",
+                    args, field_name
+                )));
+            }
+            let _ = writeln!(
+                struct_fields,
+                "    #[doc = {:?}]\n    pub {}: ::std::string::String,",
+                format!("`{}`", field),
+                field_name
+            );
+            let _ = writeln!(
+                entries,
+                "            (::std::string::String::from({:?}), args.{}),",
+                field, field_name
+            );
+        }
+        let _ = writeln!(
+            source,
+            "pub fn {}(handler: &::yew_translator::I18nHandler, args: {}) -> ::std::string::String {{\n    handler.tth(\n        {:?},\n        &::std::collections::HashMap::from([\n{}        ]),\n    )\n}}\n",
+            name, args, key, entries
+        );
+        let _ = writeln!(
+            source,
+            "#[doc = {:?}]\n#[derive(Debug, Clone, Default, PartialEq)]\npub struct {} {{\n{}}}\n",
+            format!("Data of the template `{}`.", key),
+            args,
+            struct_fields
+        );
+    }
+    Ok(source)
+}
+
+/// Read the catalog of the source language and write the module of its key accessors into `out_file`.
+/// Meant to be called from a build script, it tells cargo to run it again when the catalog changes.
+pub fn generate_keys(
+    catalog: impl AsRef<Path>,
+    out_file: impl AsRef<Path>,
+) -> Result<(), CodegenError> {
+    let catalog = catalog.as_ref();
+    println!("cargo:rerun-if-changed={}", catalog.display());
+    let content =
+        std::fs::read_to_string(catalog).map_err(|err| CodegenError::Io(err.to_string()))?;
+    let json: serde_json::Value =
+        serde_json::from_str(&content).map_err(|err| CodegenError::Parse(err.to_string()))?;
+    let source = format!(
+        "// Generated by yew_translator from `{}`, do not edit.\n\n{}",
+        catalog.display(),
+        generate_keys_module(&encode_json_to_hashmap(&json))?
+    );
+    if std::fs::read_to_string(out_file.as_ref()).ok().as_ref() != Some(&source) {
+        std::fs::write(out_file, source).map_err(|err| CodegenError::Io(err.to_string()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::generate_keys_module;
+
+    #[test]
+    fn test_generate_keys_module() {
+        let translations = HashMap::from([
+            ("menu.home".to_string(), "Home".to_string()),
+            ("hello".to_string(), "Hello {{name}}.".to_string()),
+            ("hard_hello".to_string(), "Hmm... {{{hello}}}".to_string()),
+        ]);
+        let source = generate_keys_module(&translations).unwrap();
+        assert!(source.contains("pub fn menu_home(handler: &::yew_translator::I18nHandler)"));
+        assert!(source
+            .contains("pub fn hello(handler: &::yew_translator::I18nHandler, args: HelloArgs)"));
+        assert!(source.contains("pub struct HardHelloArgs {"));
+        assert!(source.contains("    pub name: ::std::string::String,"));
+    }
+}
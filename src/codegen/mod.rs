@@ -0,0 +1,11 @@
+mod check;
+mod errors;
+mod extract;
+mod keys;
+
+pub use check::{
+    check_catalogs, check_placeholders, load_locales, CatalogIssue, CatalogIssueKind, Catalogs,
+};
+pub use errors::CodegenError;
+pub use extract::{add_keys, extract_keys, extract_keys_from_dir, unused_keys};
+pub use keys::{generate_keys, generate_keys_module};
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::FormatError;
+
+/// Parse a YAML catalog into the JSON translations of a language.
+///
+/// Mapping keys that aren't strings are written as strings, and the Rails `%{name}`
+/// interpolations become `{{name}}` placeholders.
+#[cfg(feature = "yaml")]
+pub fn parse_yaml(source: &str) -> Result<Value, FormatError> {
+    let yaml: serde_yaml::Value =
+        serde_yaml::from_str(source).map_err(|err| FormatError::Parse(err.to_string()))?;
+    Ok(yaml_to_json(yaml))
+}
+
+/// Parse a TOML catalog into the JSON translations of a language.
+///
+/// Dates are written as strings, and the Rails `%{name}` interpolations become `{{name}}` placeholders.
+#[cfg(feature = "toml")]
+pub fn parse_toml(source: &str) -> Result<Value, FormatError> {
+    let toml: toml::Table =
+        toml::from_str(source).map_err(|err| FormatError::Parse(err.to_string()))?;
+    Ok(toml_to_json(toml::Value::Table(toml)))
+}
+
+/// Read a JSON, YAML (`.yaml`, `.yml`) or TOML (`.toml`) catalog depending on its extension.
+pub fn read_catalog(path: impl AsRef<Path>) -> Result<Value, FormatError> {
+    let path = path.as_ref();
+    let source = std::fs::read_to_string(path).map_err(|err| FormatError::Io(err.to_string()))?;
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default();
+    match extension {
+        "json" => serde_json::from_str(&source).map_err(|err| FormatError::Parse(err.to_string())),
+        #[cfg(feature = "yaml")]
+        "yaml" | "yml" => parse_yaml(&source),
+        #[cfg(feature = "toml")]
+        "toml" => parse_toml(&source),
+        _ => Err(FormatError::Parse(format!(
+            "Unsupported catalog format: `{}`",
+            path.display()
+        ))),
+    }
+}
+
+/// Split a catalog whose top-level keys are languages, as the Rails locale files (`en: { … }`),
+/// into the translations of each language.
+pub fn split_languages(catalog: Value) -> Result<HashMap<String, Value>, FormatError> {
+    match catalog {
+        Value::Object(languages) => Ok(languages.into_iter().collect()),
+        _ => Err(FormatError::Parse(
+            "The catalog should map languages to their translations".to_string(),
+        )),
+    }
+}
+
+#[cfg(feature = "yaml")]
+fn yaml_to_json(yaml: serde_yaml::Value) -> Value {
+    match yaml {
+        serde_yaml::Value::Null => Value::Null,
+        serde_yaml::Value::Bool(value) => Value::Bool(value),
+        serde_yaml::Value::Number(number) => serde_json::to_value(number).unwrap_or(Value::Null),
+        serde_yaml::Value::String(value) => Value::String(convert_interpolations(&value)),
+        serde_yaml::Value::Sequence(values) => {
+            Value::Array(values.into_iter().map(yaml_to_json).collect())
+        }
+        serde_yaml::Value::Mapping(mapping) => Value::Object(
+            mapping
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = match key {
+                        serde_yaml::Value::String(key) => key,
+                        key => match yaml_to_json(key) {
+                            Value::String(key) => key,
+                            key => key.to_string(),
+                        },
+                    };
+                    (key, yaml_to_json(value))
+                })
+                .collect::<serde_json::Map<_, _>>(),
+        ),
+        serde_yaml::Value::Tagged(tagged) => yaml_to_json(tagged.value),
+    }
+}
+
+#[cfg(feature = "toml")]
+fn toml_to_json(toml: toml::Value) -> Value {
+    match toml {
+        toml::Value::String(value) => Value::String(convert_interpolations(&value)),
+        toml::Value::Integer(value) => Value::from(value),
+        toml::Value::Float(value) => Value::from(value),
+        toml::Value::Boolean(value) => Value::Bool(value),
+        toml::Value::Datetime(value) => Value::String(value.to_string()),
+        toml::Value::Array(values) => Value::Array(values.into_iter().map(toml_to_json).collect()),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, toml_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Turn the Rails `%{name}` interpolations into `{{name}}` placeholders, and the escaped `%%{name}` into
+/// a literal `%{name}`.
+fn convert_interpolations(value: &str) -> String {
+    let mut result = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("%{") {
+        let escaped = rest[..start].ends_with('%');
+        let Some(end) = rest[start..].find('}').map(|end| start + end) else {
+            break;
+        };
+        let name = &rest[start + 2..end];
+        if escaped {
+            result.push_str(&rest[..start - 1]);
+            result.push_str(&format!("%\\{{{}\\}}", name));
+        } else {
+            result.push_str(&rest[..start]);
+            result.push_str(&format!("{{{{{}}}}}", name));
+        }
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::convert_interpolations;
+
+    #[test]
+    fn test_convert_interpolations() {
+        assert_eq!(
+            convert_interpolations("Hello %{name}, {{count}} new"),
+            "Hello {{name}}, {{count}} new"
+        );
+        assert_eq!(
+            convert_interpolations("Write %%{name}"),
+            "Write %\\{name\\}"
+        );
+        assert_eq!(convert_interpolations("100% %{"), "100% %{");
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    fn test_parse_yaml() {
+        let catalog = super::parse_yaml(
+            "en:\n  greeting: Hello %{name}\n  inbox:\n    one: One message\n    other: '%{count} messages'\n  1: true\nfr:\n  greeting: Bonjour %{name}\n",
+        )
+        .unwrap();
+        let languages = super::split_languages(catalog).unwrap();
+        assert_eq!(
+            languages["en"],
+            json!({
+                "greeting": "Hello {{name}}",
+                "inbox": { "one": "One message", "other": "{{count}} messages" },
+                "1": true,
+            })
+        );
+        assert_eq!(languages["fr"], json!({ "greeting": "Bonjour {{name}}" }));
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn test_parse_toml() {
+        let catalog = super::parse_toml(
+            "greeting = \"Hello %{name}\"\n\n[menu]\nitems = [\"Home\", \"About\"]\n",
+        )
+        .unwrap();
+        assert_eq!(
+            catalog,
+            json!({ "greeting": "Hello {{name}}", "menu": { "items": ["Home", "About"] } })
+        );
+    }
+}
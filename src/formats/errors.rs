@@ -0,0 +1,8 @@
+/// Any errors that might occurs while reading a translation file.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum FormatError {
+    #[error("IO error: `{0}`")]
+    Io(String),
+    #[error("Parse error: `{0}`")]
+    Parse(String),
+}
@@ -0,0 +1,432 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::{FormatError, ImportedTranslations};
+
+/// Conversions of the `printf` and python format strings turned into placeholders.
+const CONVERSIONS: &str = "sdiufFeEgGxXoc";
+
+/// A message of a `.po` file, being read.
+#[derive(Default)]
+struct PoEntry {
+    context: Option<String>,
+    id: Option<String>,
+    id_plural: Option<String>,
+    strings: BTreeMap<usize, String>,
+    flags: Vec<String>,
+}
+
+/// The field of a `.po` entry the continuation lines are appended to.
+#[derive(Clone, Copy)]
+enum PoField {
+    Context,
+    Id,
+    IdPlural,
+    Str(usize),
+}
+
+/// Import the translations of a gettext `.po` file.
+///
+/// Messages are keyed by their `msgid`, nested under their `msgctxt` if any, while the forms of
+/// plural messages are imported as an array (`key.0`, `key.1`, ...) following the `Plural-Forms` indexes.
+/// The `%s`, `%1$s` and `%(name)s` formats become `{{0}}`, `{{0}}` and `{{name}}` placeholders.
+/// Fuzzy, untranslated and unconvertible messages are skipped and reported.
+pub fn parse_po(source: &str) -> Result<ImportedTranslations, FormatError> {
+    let mut imported = ImportedTranslations::default();
+    let mut entry = PoEntry::default();
+    let mut field = None;
+    for (index, line) in source.lines().enumerate() {
+        let line = line.trim();
+        let parse_error = |message: &str| {
+            FormatError::Parse(format!("line {}: {} `{}`", index + 1, message, line))
+        };
+        if line.is_empty() || line.starts_with("#~") {
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            if entry.id.is_some() {
+                add_po_entry(&mut imported, std::mem::take(&mut entry));
+                field = None;
+            }
+            if let Some(flags) = comment.strip_prefix(',') {
+                entry
+                    .flags
+                    .extend(flags.split(',').map(|flag| flag.trim().to_string()));
+            }
+            continue;
+        }
+        if line.starts_with('"') {
+            let value = unquote(line).ok_or_else(|| parse_error("Invalid string"))?;
+            let target = match field {
+                Some(PoField::Context) => entry.context.as_mut(),
+                Some(PoField::Id) => entry.id.as_mut(),
+                Some(PoField::IdPlural) => entry.id_plural.as_mut(),
+                Some(PoField::Str(index)) => entry.strings.get_mut(&index),
+                None => None,
+            };
+            target
+                .ok_or_else(|| parse_error("Unexpected string"))?
+                .push_str(&value);
+            continue;
+        }
+        let (keyword, value) = line
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| parse_error("Unexpected line"))?;
+        let value = unquote(value.trim()).ok_or_else(|| parse_error("Invalid string"))?;
+        let next_field = match keyword {
+            "msgctxt" => PoField::Context,
+            "msgid" => PoField::Id,
+            "msgid_plural" => PoField::IdPlural,
+            "msgstr" => PoField::Str(0),
+            _ => keyword
+                .strip_prefix("msgstr[")
+                .and_then(|index| index.strip_suffix(']'))
+                .and_then(|index| index.parse().ok())
+                .map(PoField::Str)
+                .ok_or_else(|| parse_error("Unknown keyword"))?,
+        };
+        if matches!(next_field, PoField::Context | PoField::Id) && entry.id.is_some() {
+            add_po_entry(&mut imported, std::mem::take(&mut entry));
+        }
+        match next_field {
+            PoField::Context => entry.context = Some(value),
+            PoField::Id => entry.id = Some(value),
+            PoField::IdPlural => entry.id_plural = Some(value),
+            PoField::Str(index) => {
+                entry.strings.insert(index, value);
+            }
+        }
+        field = Some(next_field);
+    }
+    if entry.id.is_some() {
+        add_po_entry(&mut imported, entry);
+    }
+    Ok(imported)
+}
+
+/// Import the translations of a compiled gettext `.mo` file, the same way as `parse_po`.
+pub fn parse_mo(bytes: &[u8]) -> Result<ImportedTranslations, FormatError> {
+    let truncated = || FormatError::Parse("Truncated `.mo` file".to_string());
+    let read_u32 = |offset: usize, little_endian: bool| -> Result<usize, FormatError> {
+        let bytes: [u8; 4] = bytes
+            .get(offset..offset + 4)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(truncated)?;
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        } as usize)
+    };
+    let little_endian = match read_u32(0, true)? {
+        0x950412de => true,
+        0xde120495 => false,
+        _ => return Err(FormatError::Parse("Invalid `.mo` magic number".to_string())),
+    };
+    let read_string = |table: usize, index: usize| -> Result<&str, FormatError> {
+        let length = read_u32(table + index * 8, little_endian)?;
+        let offset = read_u32(table + index * 8 + 4, little_endian)?;
+        let bytes = bytes.get(offset..offset + length).ok_or_else(truncated)?;
+        std::str::from_utf8(bytes).map_err(|err| FormatError::Parse(err.to_string()))
+    };
+    let count = read_u32(8, little_endian)?;
+    let originals = read_u32(12, little_endian)?;
+    let translations = read_u32(16, little_endian)?;
+
+    let mut imported = ImportedTranslations::default();
+    for index in 0..count {
+        let original = read_string(originals, index)?;
+        let (context, original) = match original.split_once('\u{4}') {
+            Some((context, original)) => (Some(context), original),
+            None => (None, original),
+        };
+        let (id, plural) = match original.split_once('\0') {
+            Some((id, _)) => (id, true),
+            None => (original, false),
+        };
+        let forms = read_string(translations, index)?
+            .split('\0')
+            .map(str::to_string)
+            .collect();
+        add_message(&mut imported, context, id, plural, forms, &[]);
+    }
+    Ok(imported)
+}
+
+/// Import the translations of a gettext file, compiled (`.mo`) or not (`.po`) depending on its extension.
+pub fn read_gettext(path: impl AsRef<Path>) -> Result<ImportedTranslations, FormatError> {
+    let path = path.as_ref();
+    if path.extension().is_some_and(|extension| extension == "mo") {
+        let bytes = std::fs::read(path).map_err(|err| FormatError::Io(err.to_string()))?;
+        parse_mo(&bytes)
+    } else {
+        let source =
+            std::fs::read_to_string(path).map_err(|err| FormatError::Io(err.to_string()))?;
+        parse_po(&source)
+    }
+}
+
+fn add_po_entry(imported: &mut ImportedTranslations, entry: PoEntry) {
+    let Some(id) = entry.id else {
+        return;
+    };
+    let forms = entry.strings.into_values().collect();
+    add_message(
+        imported,
+        entry.context.as_deref(),
+        &id,
+        entry.id_plural.is_some(),
+        forms,
+        &entry.flags,
+    );
+}
+
+/// Convert a message into a translation, or report why it can't be.
+fn add_message(
+    imported: &mut ImportedTranslations,
+    context: Option<&str>,
+    id: &str,
+    plural: bool,
+    forms: Vec<String>,
+    flags: &[String],
+) {
+    // The header holds the metadata of the file.
+    if id.is_empty() && context.is_none() {
+        return;
+    }
+    let path: Vec<&str> = context.into_iter().chain([id]).collect();
+    let key = path.join(".");
+    if flags.iter().any(|flag| flag == "fuzzy") {
+        return imported.skip(key, "fuzzy translation");
+    }
+    if forms.iter().all(String::is_empty) {
+        return imported.skip(key, "untranslated");
+    }
+    let formatted = !flags
+        .iter()
+        .any(|flag| flag.starts_with("no-") && flag.ends_with("-format"));
+    let forms = match forms
+        .iter()
+        .map(|form| convert_format(form, formatted))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(forms) => forms,
+        Err(reason) => return imported.skip(key, reason),
+    };
+    let value = if plural {
+        Value::Array(forms.into_iter().map(Value::String).collect())
+    } else {
+        Value::String(forms.into_iter().next().unwrap_or_default())
+    };
+    imported.insert(&path, value);
+}
+
+/// Convert a gettext message into a template, escaping its brackets and, if `formatted`,
+/// turning its formats into placeholders.
+fn convert_format(message: &str, formatted: bool) -> Result<String, String> {
+    let mut result = String::new();
+    let mut position = 0;
+    let mut chars = message.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' | '\\' => {
+                result.push('\\');
+                result.push(c);
+            }
+            '%' if formatted => {
+                if chars.next_if_eq(&'%').is_some() {
+                    result.push('%');
+                    continue;
+                }
+                let mut spec = String::from('%');
+                let mut name = None;
+                if chars.next_if_eq(&'(').is_some() {
+                    let mut named = String::new();
+                    for c in chars.by_ref() {
+                        if c == ')' {
+                            break;
+                        }
+                        named.push(c);
+                    }
+                    spec.push_str(&format!("({})", named));
+                    name = Some(named);
+                }
+                let mut modifiers = String::new();
+                while let Some(c) = chars.next_if(|c| {
+                    !CONVERSIONS.contains(*c) && " #+-.*0123456789$hlLqjzt".contains(*c)
+                }) {
+                    modifiers.push(c);
+                }
+                spec.push_str(&modifiers);
+                let Some(conversion) = chars.next_if(|c| CONVERSIONS.contains(*c)) else {
+                    return Err(format!("unsupported format `{}`", spec));
+                };
+                spec.push(conversion);
+                let modifiers = modifiers.trim_end_matches(|c: char| "hlLqjzt".contains(c));
+                let name = match (name, modifiers.strip_suffix('$')) {
+                    (Some(name), None) if modifiers.is_empty() => name,
+                    (None, None) if modifiers.is_empty() => {
+                        position += 1;
+                        (position - 1).to_string()
+                    }
+                    (None, Some(index)) => match index.parse::<usize>() {
+                        Ok(index) if index > 0 => (index - 1).to_string(),
+                        _ => return Err(format!("unsupported format `{}`", spec)),
+                    },
+                    _ => return Err(format!("unsupported format `{}`", spec)),
+                };
+                result.push_str(&format!("{{{{{}}}}}", name));
+            }
+            _ => result.push(c),
+        }
+    }
+    Ok(result)
+}
+
+/// Read a quoted `.po` string, unescaping it.
+fn unquote(value: &str) -> Option<String> {
+    let value = value.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => result.push('\n'),
+            't' => result.push('\t'),
+            'r' => result.push('\r'),
+            'a' => result.push('\u{7}'),
+            'b' => result.push('\u{8}'),
+            'f' => result.push('\u{c}'),
+            'v' => result.push('\u{b}'),
+            c => result.push(c),
+        }
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{parse_mo, parse_po};
+    use crate::formats::SkippedEntry;
+
+    #[test]
+    fn test_parse_po() {
+        let imported = parse_po(
+            r#"
+msgid ""
+msgstr ""
+"Language: fr\n"
+"Plural-Forms: nplurals=2; plural=(n > 1);\n"
+
+#: src/main.rs:1
+msgid "Hello %(name)s, you have %d {new} messages"
+msgstr "Bonjour %(name)s, vous avez %d {nouveaux} messages"
+
+msgctxt "menu"
+msgid "Open"
+msgstr "Ouvrir"
+
+msgid "One file"
+msgid_plural "%d files"
+msgstr[0] "Un fichier"
+msgstr[1] "%d "
+"fichiers"
+
+#, fuzzy
+msgid "Close"
+msgstr "Fermer"
+
+msgid "Save"
+msgstr ""
+
+#, c-format
+msgid "%5d items"
+msgstr "%5d éléments"
+
+#, no-c-format
+msgid "100%"
+msgstr "100%"
+
+#~ msgid "Old"
+#~ msgstr "Vieux"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            imported.translations,
+            json!({
+                "Hello %(name)s, you have %d {new} messages": "Bonjour {{name}}, vous avez {{0}} \\{nouveaux\\} messages",
+                "menu": { "Open": "Ouvrir" },
+                "One file": ["Un fichier", "{{0}} fichiers"],
+                "100%": "100%",
+            })
+        );
+        assert_eq!(
+            imported.skipped,
+            vec![
+                SkippedEntry {
+                    key: "Close".to_string(),
+                    reason: "fuzzy translation".to_string()
+                },
+                SkippedEntry {
+                    key: "Save".to_string(),
+                    reason: "untranslated".to_string()
+                },
+                SkippedEntry {
+                    key: "%5d items".to_string(),
+                    reason: "unsupported format `%5d`".to_string()
+                },
+            ]
+        );
+        assert!(parse_po("msgid \"unterminated").is_err());
+    }
+
+    #[test]
+    fn test_parse_mo() {
+        let originals = ["", "One file\0%d files", "menu\u{4}Open"];
+        let translations = ["Language: fr\n", "Un fichier\0%d fichiers", "Ouvrir"];
+        let mut bytes = Vec::new();
+        let mut data = Vec::new();
+        let data_offset = 28 + originals.len() * 16;
+        let mut tables = Vec::new();
+        for string in originals.iter().chain(translations.iter()) {
+            tables.push((string.len() as u32, (data_offset + data.len()) as u32));
+            data.extend_from_slice(string.as_bytes());
+            data.push(0);
+        }
+        for value in [
+            0x950412de,
+            0,
+            originals.len() as u32,
+            28,
+            28 + originals.len() as u32 * 8,
+            0,
+            0,
+        ] {
+            bytes.extend_from_slice(&u32::to_le_bytes(value));
+        }
+        for (length, offset) in tables {
+            bytes.extend_from_slice(&length.to_le_bytes());
+            bytes.extend_from_slice(&offset.to_le_bytes());
+        }
+        bytes.extend_from_slice(&data);
+
+        let imported = parse_mo(&bytes).unwrap();
+        assert_eq!(
+            imported.translations,
+            json!({
+                "menu": { "Open": "Ouvrir" },
+                "One file": ["Un fichier", "{{0}} fichiers"],
+            })
+        );
+        assert!(imported.skipped.is_empty());
+        assert!(parse_mo(&bytes[..20]).is_err());
+    }
+}
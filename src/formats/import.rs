@@ -0,0 +1,58 @@
+use serde_json::{Map, Value};
+
+/// An entry of a translation file that could not be imported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedEntry {
+    /// The key the entry would have had.
+    pub key: String,
+    /// Why the entry was skipped.
+    pub reason: String,
+}
+
+/// The translations of one language imported from a translation file, ready to be given to
+/// `I18nProviderProps::translations`, along with the entries that could not be imported.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ImportedTranslations {
+    /// The imported translations, as a JSON object.
+    pub translations: Value,
+    /// The entries that could not be imported.
+    pub skipped: Vec<SkippedEntry>,
+}
+
+impl ImportedTranslations {
+    /// Report an entry that could not be imported.
+    pub(crate) fn skip(&mut self, key: impl Into<String>, reason: impl Into<String>) {
+        self.skipped.push(SkippedEntry {
+            key: key.into(),
+            reason: reason.into(),
+        });
+    }
+
+    /// Insert a value at the path of objects given, skipping it if it conflicts with another entry.
+    pub(crate) fn insert(&mut self, path: &[&str], value: Value) {
+        if !self.translations.is_object() {
+            self.translations = Value::Object(Map::new());
+        }
+        if !insert_at(&mut self.translations, path, value) {
+            self.skip(path.join("."), "conflicts with another entry");
+        }
+    }
+}
+
+/// Insert a value at the path of objects given, unless another value is already there.
+fn insert_at(object: &mut Value, path: &[&str], value: Value) -> bool {
+    let (Some(object), Some((first, rest))) = (object.as_object_mut(), path.split_first()) else {
+        return false;
+    };
+    if rest.is_empty() {
+        if object.contains_key(*first) {
+            return false;
+        }
+        object.insert(first.to_string(), value);
+        return true;
+    }
+    let child = object
+        .entry(first.to_string())
+        .or_insert_with(|| Value::Object(Map::new()));
+    insert_at(child, rest, value)
+}
@@ -0,0 +1,23 @@
+#[cfg(any(feature = "yaml", feature = "toml"))]
+mod catalog;
+mod errors;
+#[cfg(feature = "gettext")]
+mod gettext;
+#[cfg(any(feature = "gettext", feature = "xliff"))]
+mod import;
+#[cfg(feature = "xliff")]
+mod xliff;
+
+#[cfg(feature = "toml")]
+pub use catalog::parse_toml;
+#[cfg(feature = "yaml")]
+pub use catalog::parse_yaml;
+#[cfg(any(feature = "yaml", feature = "toml"))]
+pub use catalog::{read_catalog, split_languages};
+pub use errors::FormatError;
+#[cfg(feature = "gettext")]
+pub use gettext::{parse_mo, parse_po, read_gettext};
+#[cfg(any(feature = "gettext", feature = "xliff"))]
+pub use import::{ImportedTranslations, SkippedEntry};
+#[cfg(feature = "xliff")]
+pub use xliff::{XliffDocument, XliffState, XliffUnit, XliffVersion};
@@ -0,0 +1,523 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use super::{FormatError, ImportedTranslations};
+
+/// The version of an XLIFF document.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum XliffVersion {
+    /// XLIFF 1.2, using `<trans-unit>` elements.
+    V1_2,
+    /// XLIFF 2.0, using `<unit>` and `<segment>` elements.
+    #[default]
+    V2_0,
+}
+
+/// The progress of the translation of a unit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum XliffState {
+    /// Not translated yet (`new` in XLIFF 1.2, `initial` in XLIFF 2.0).
+    #[default]
+    New,
+    /// Translated, waiting for a review.
+    Translated,
+    /// Reviewed (`signed-off` in XLIFF 1.2).
+    Reviewed,
+    /// Finalized.
+    Final,
+}
+
+impl XliffState {
+    fn as_str(&self, version: XliffVersion) -> &'static str {
+        match (self, version) {
+            (XliffState::New, XliffVersion::V1_2) => "new",
+            (XliffState::New, XliffVersion::V2_0) => "initial",
+            (XliffState::Translated, _) => "translated",
+            (XliffState::Reviewed, XliffVersion::V1_2) => "signed-off",
+            (XliffState::Reviewed, XliffVersion::V2_0) => "reviewed",
+            (XliffState::Final, _) => "final",
+        }
+    }
+
+    fn parse(state: &str) -> Self {
+        match state {
+            "translated"
+            | "needs-review-translation"
+            | "needs-review-adaptation"
+            | "needs-review-l10n" => XliffState::Translated,
+            "reviewed" | "signed-off" => XliffState::Reviewed,
+            "final" => XliffState::Final,
+            _ => XliffState::New,
+        }
+    }
+}
+
+/// A translation unit of an XLIFF document.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct XliffUnit {
+    /// The key of the translation.
+    pub key: String,
+    /// The template in the source language.
+    pub source: String,
+    /// The template in the target language, if translated.
+    pub target: Option<String>,
+    /// A note for the translators.
+    pub note: Option<String>,
+    /// The progress of the translation.
+    pub state: XliffState,
+}
+
+/// An XLIFF document holding the translations of a source and a target language.
+///
+/// The `{{…}}` and `{{{…}}}` placeholders of the templates are protected as inline `<ph>` elements,
+/// so translation tools don't alter them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct XliffDocument {
+    /// The version of the document.
+    pub version: XliffVersion,
+    /// The source language code.
+    pub source_language: String,
+    /// The target language code.
+    pub target_language: String,
+    /// The translation units, sorted by key when exported from catalogs.
+    pub units: Vec<XliffUnit>,
+}
+
+impl XliffDocument {
+    /// Create a document from the flattened translations of a source and a target language,
+    /// with one unit per key of the source.
+    pub fn new(
+        version: XliffVersion,
+        source_language: impl Into<String>,
+        source: &HashMap<String, String>,
+        target_language: impl Into<String>,
+        target: &HashMap<String, String>,
+    ) -> Self {
+        let sorted: BTreeMap<&String, &String> = source.iter().collect();
+        let units = sorted
+            .into_iter()
+            .map(|(key, source)| {
+                let target = target.get(key).cloned();
+                XliffUnit {
+                    key: key.clone(),
+                    source: source.clone(),
+                    state: if target.is_some() {
+                        XliffState::Translated
+                    } else {
+                        XliffState::New
+                    },
+                    target,
+                    note: None,
+                }
+            })
+            .collect();
+        Self {
+            version,
+            source_language: source_language.into(),
+            target_language: target_language.into(),
+            units,
+        }
+    }
+
+    /// Set the note of the unit of a key.
+    pub fn with_note(mut self, key: &str, note: impl Into<String>) -> Self {
+        if let Some(unit) = self.units.iter_mut().find(|unit| unit.key == key) {
+            unit.note = Some(note.into());
+        }
+        self
+    }
+
+    /// Set the state of the unit of a key.
+    pub fn with_state(mut self, key: &str, state: XliffState) -> Self {
+        if let Some(unit) = self.units.iter_mut().find(|unit| unit.key == key) {
+            unit.state = state;
+        }
+        self
+    }
+
+    /// Write the document as XLIFF.
+    pub fn to_xliff(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        match self.version {
+            XliffVersion::V1_2 => {
+                let _ = writeln!(
+                    xml,
+                    "<xliff version=\"1.2\" xmlns=\"urn:oasis:names:tc:xliff:document:1.2\">\n  <file original=\"translations\" datatype=\"plaintext\" source-language=\"{}\" target-language=\"{}\">\n    <body>",
+                    escape(&self.source_language),
+                    escape(&self.target_language)
+                );
+                for unit in &self.units {
+                    let mut ids = PlaceholderIds::default();
+                    let _ = writeln!(
+                        xml,
+                        "      <trans-unit id=\"{0}\" resname=\"{0}\">\n        <source>{1}</source>",
+                        escape(&unit.key),
+                        ids.protect_1_2(&unit.source)
+                    );
+                    if let Some(target) = &unit.target {
+                        let _ = writeln!(
+                            xml,
+                            "        <target state=\"{}\">{}</target>",
+                            unit.state.as_str(self.version),
+                            ids.protect_1_2(target)
+                        );
+                    }
+                    if let Some(note) = &unit.note {
+                        let _ = writeln!(xml, "        <note>{}</note>", escape(note));
+                    }
+                    xml.push_str("      </trans-unit>\n");
+                }
+                xml.push_str("    </body>\n  </file>\n</xliff>\n");
+            }
+            XliffVersion::V2_0 => {
+                let _ = writeln!(
+                    xml,
+                    "<xliff version=\"2.0\" xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" srcLang=\"{}\" trgLang=\"{}\">\n  <file id=\"f1\">",
+                    escape(&self.source_language),
+                    escape(&self.target_language)
+                );
+                for (index, unit) in self.units.iter().enumerate() {
+                    let mut ids = PlaceholderIds::default();
+                    let source = ids.protect_2_0(&unit.source);
+                    let target = unit.target.as_ref().map(|target| ids.protect_2_0(target));
+                    let _ = writeln!(
+                        xml,
+                        "    <unit id=\"u{}\" name=\"{}\">",
+                        index + 1,
+                        escape(&unit.key)
+                    );
+                    if let Some(note) = &unit.note {
+                        let _ = writeln!(
+                            xml,
+                            "      <notes>\n        <note>{}</note>\n      </notes>",
+                            escape(note)
+                        );
+                    }
+                    if !ids.placeholders.is_empty() {
+                        xml.push_str("      <originalData>\n");
+                        for (index, placeholder) in ids.placeholders.iter().enumerate() {
+                            let _ = writeln!(
+                                xml,
+                                "        <data id=\"d{}\">{}</data>",
+                                index + 1,
+                                escape(placeholder)
+                            );
+                        }
+                        xml.push_str("      </originalData>\n");
+                    }
+                    let _ = writeln!(
+                        xml,
+                        "      <segment state=\"{}\">\n        <source>{}</source>",
+                        unit.state.as_str(self.version),
+                        source
+                    );
+                    if let Some(target) = target {
+                        let _ = writeln!(xml, "        <target>{}</target>", target);
+                    }
+                    xml.push_str("      </segment>\n    </unit>\n");
+                }
+                xml.push_str("  </file>\n</xliff>\n");
+            }
+        }
+        xml
+    }
+
+    /// Read an XLIFF 1.2 or 2.0 document, restoring the placeholders of its `<ph>` elements.
+    pub fn parse(xml: &str) -> Result<Self, FormatError> {
+        let mut reader = Reader::from_str(xml);
+        let mut document = XliffDocument::default();
+        let mut unit: Option<XliffUnit> = None;
+        let mut data = HashMap::new();
+        let mut data_id: Option<String> = None;
+        let mut text: Option<String> = None;
+        loop {
+            let event = reader
+                .read_event()
+                .map_err(|err| FormatError::Parse(err.to_string()))?;
+            match event {
+                Event::Start(ref element) => match element.local_name().as_ref() {
+                    b"xliff" => {
+                        if attribute(element, "version")?.as_deref() == Some("1.2") {
+                            document.version = XliffVersion::V1_2;
+                        }
+                        document.source_language =
+                            attribute(element, "srcLang")?.unwrap_or_default();
+                        document.target_language =
+                            attribute(element, "trgLang")?.unwrap_or_default();
+                    }
+                    b"file" if document.version == XliffVersion::V1_2 => {
+                        document.source_language =
+                            attribute(element, "source-language")?.unwrap_or_default();
+                        document.target_language =
+                            attribute(element, "target-language")?.unwrap_or_default();
+                    }
+                    b"trans-unit" | b"unit" => {
+                        let key = match attribute(element, "resname")? {
+                            Some(key) => Some(key),
+                            None => attribute(element, "name")?,
+                        };
+                        let key = match key {
+                            Some(key) => key,
+                            None => attribute(element, "id")?.unwrap_or_default(),
+                        };
+                        data.clear();
+                        unit = Some(XliffUnit {
+                            key,
+                            ..Default::default()
+                        });
+                    }
+                    b"segment" => {
+                        if let (Some(unit), Some(state)) =
+                            (unit.as_mut(), attribute(element, "state")?)
+                        {
+                            unit.state = XliffState::parse(&state);
+                        }
+                    }
+                    b"data" => {
+                        data_id = attribute(element, "id")?;
+                        text = Some(String::new());
+                    }
+                    b"target" => {
+                        if let (Some(unit), Some(state)) =
+                            (unit.as_mut(), attribute(element, "state")?)
+                        {
+                            unit.state = XliffState::parse(&state);
+                        }
+                        text = Some(String::new());
+                    }
+                    b"source" | b"note" => text = Some(String::new()),
+                    _ => {}
+                },
+                Event::Empty(ref element) if element.local_name().as_ref() == b"ph" => {
+                    let placeholder = match attribute(element, "dataRef")? {
+                        Some(data_ref) => data.get(&data_ref).cloned(),
+                        None => None,
+                    };
+                    let placeholder = match placeholder {
+                        Some(placeholder) => Some(placeholder),
+                        None => attribute(element, "equiv")?,
+                    };
+                    if let (Some(text), Some(placeholder)) = (text.as_mut(), placeholder) {
+                        text.push_str(&placeholder);
+                    }
+                }
+                Event::Text(content) => {
+                    if let Some(text) = text.as_mut() {
+                        let content = content
+                            .unescape()
+                            .map_err(|err| FormatError::Parse(err.to_string()))?;
+                        text.push_str(&content);
+                    }
+                }
+                Event::CData(content) => {
+                    if let Some(text) = text.as_mut() {
+                        text.push_str(&String::from_utf8_lossy(&content));
+                    }
+                }
+                Event::End(element) => match element.local_name().as_ref() {
+                    b"trans-unit" | b"unit" => {
+                        if let Some(unit) = unit.take() {
+                            document.units.push(unit);
+                        }
+                    }
+                    b"data" => {
+                        if let (Some(id), Some(text)) = (data_id.take(), text.take()) {
+                            data.insert(id, text);
+                        }
+                    }
+                    b"source" => {
+                        if let (Some(unit), Some(text)) = (unit.as_mut(), text.take()) {
+                            unit.source = text;
+                        }
+                    }
+                    b"target" => {
+                        if let (Some(unit), Some(text)) = (unit.as_mut(), text.take()) {
+                            unit.target = Some(text);
+                        }
+                    }
+                    b"note" => {
+                        if let (Some(unit), Some(text)) = (unit.as_mut(), text.take()) {
+                            unit.note = Some(text);
+                        }
+                    }
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(document)
+    }
+
+    /// The translations of the target language, as the JSON value expected by `I18nProviderProps::translations`.
+    /// The untranslated units are skipped and reported.
+    pub fn target_translations(&self) -> ImportedTranslations {
+        let mut imported = ImportedTranslations::default();
+        for unit in &self.units {
+            match unit.target.as_ref().filter(|target| !target.is_empty()) {
+                Some(target) => {
+                    let path: Vec<&str> = unit.key.split('.').collect();
+                    imported.insert(&path, serde_json::Value::String(target.clone()));
+                }
+                None => imported.skip(unit.key.clone(), "untranslated"),
+            }
+        }
+        imported
+    }
+}
+
+/// The placeholders of a unit, numbered in order of appearance.
+#[derive(Default)]
+struct PlaceholderIds {
+    placeholders: Vec<String>,
+}
+
+impl PlaceholderIds {
+    fn id(&mut self, placeholder: &str) -> usize {
+        match self
+            .placeholders
+            .iter()
+            .position(|known| known == placeholder)
+        {
+            Some(index) => index + 1,
+            None => {
+                self.placeholders.push(placeholder.to_string());
+                self.placeholders.len()
+            }
+        }
+    }
+
+    /// Escape a template, writing its placeholders as `<ph>` elements holding them.
+    fn protect_1_2(&mut self, template: &str) -> String {
+        split_placeholders(template)
+            .into_iter()
+            .map(|(placeholder, part)| {
+                if placeholder {
+                    format!("<ph id=\"{}\">{}</ph>", self.id(part), escape(part))
+                } else {
+                    escape(part).into_owned()
+                }
+            })
+            .collect()
+    }
+
+    /// Escape a template, writing its placeholders as `<ph>` elements referencing the original data.
+    fn protect_2_0(&mut self, template: &str) -> String {
+        split_placeholders(template)
+            .into_iter()
+            .map(|(placeholder, part)| {
+                if placeholder {
+                    let id = self.id(part);
+                    format!(
+                        "<ph id=\"ph{0}\" dataRef=\"d{0}\" equiv=\"{1}\"/>",
+                        id,
+                        escape(part)
+                    )
+                } else {
+                    escape(part).into_owned()
+                }
+            })
+            .collect()
+    }
+}
+
+/// Split a template into its text and its `{{…}}` or `{{{…}}}` placeholders, flagged with `true`.
+fn split_placeholders(template: &str) -> Vec<(bool, &str)> {
+    let mut parts = Vec::new();
+    let mut text_start = 0;
+    let mut index = 0;
+    let bytes = template.as_bytes();
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b'{' if bytes.get(index + 1) == Some(&b'{') => {
+                let close = if bytes.get(index + 2) == Some(&b'{') {
+                    "}}}"
+                } else {
+                    "}}"
+                };
+                let Some(end) = template[index..].find(close) else {
+                    break;
+                };
+                if text_start < index {
+                    parts.push((false, &template[text_start..index]));
+                }
+                let end = index + end + close.len();
+                parts.push((true, &template[index..end]));
+                index = end;
+                text_start = end;
+            }
+            _ => index += 1,
+        }
+    }
+    if text_start < template.len() {
+        parts.push((false, &template[text_start..]));
+    }
+    parts
+}
+
+/// Read an attribute of an element, unescaped.
+fn attribute(element: &BytesStart, name: &str) -> Result<Option<String>, FormatError> {
+    element
+        .try_get_attribute(name)
+        .map_err(|err| FormatError::Parse(err.to_string()))?
+        .map(|attribute| {
+            attribute
+                .unescape_value()
+                .map(|value| value.into_owned())
+                .map_err(|err| FormatError::Parse(err.to_string()))
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use super::{XliffDocument, XliffState, XliffVersion};
+
+    #[test]
+    fn test_xliff_round_trip() {
+        let source = HashMap::from([
+            ("menu.home".to_string(), "Home & <away>".to_string()),
+            (
+                "hello".to_string(),
+                "Hello {{name}}, {{{signature}}} \\{{{name}}\\}".to_string(),
+            ),
+            ("menu.about".to_string(), "About".to_string()),
+        ]);
+        let target = HashMap::from([
+            ("menu.home".to_string(), "Accueil & <ailleurs>".to_string()),
+            (
+                "hello".to_string(),
+                "{{{signature}}} Bonjour {{name}}".to_string(),
+            ),
+        ]);
+        for version in [XliffVersion::V1_2, XliffVersion::V2_0] {
+            let document = XliffDocument::new(version, "en", &source, "fr", &target)
+                .with_note("hello", "Greets the <user>")
+                .with_state("menu.home", XliffState::Final);
+            let xliff = document.to_xliff();
+            assert!(xliff.contains("<ph id="));
+            let parsed = XliffDocument::parse(&xliff).unwrap();
+            assert_eq!(parsed, document);
+
+            let imported = parsed.target_translations();
+            assert_eq!(
+                imported.translations,
+                json!({
+                    "hello": "{{{signature}}} Bonjour {{name}}",
+                    "menu": { "home": "Accueil & <ailleurs>" },
+                })
+            );
+            assert_eq!(imported.skipped.len(), 1);
+            assert_eq!(imported.skipped[0].key, "menu.about");
+        }
+    }
+}
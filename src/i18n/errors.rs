@@ -0,0 +1,26 @@
+#[cfg(feature = "translation_templater")]
+use crate::templater::StringTemplaterError;
+#[cfg(feature = "handlebars")]
+use handlebars::RenderError;
+
+/// Any errors that might occurs while translating or configuring the i18n.
+#[derive(Debug, thiserror::Error)]
+pub enum I18nError {
+    #[error("The language `{0}` is not available")]
+    UnsupportedLanguage(String),
+    #[error("Missing key `{key}` for the language `{language}`")]
+    MissingKey { language: String, key: String },
+    #[error("Missing data `{key}` for the language `{language}`")]
+    MissingData { language: String, key: String },
+    #[cfg(feature = "translation_templater")]
+    #[error("Template error: {0}")]
+    Template(#[from] StringTemplaterError),
+    #[cfg(feature = "handlebars")]
+    #[error("Handlebars error: {0}")]
+    Handlebars(#[from] RenderError),
+    #[cfg(feature = "fluent")]
+    #[error("Fluent error: {0}")]
+    Fluent(String),
+    #[error("Invalid configuration: {0}")]
+    InvalidConfiguration(String),
+}
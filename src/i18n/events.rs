@@ -0,0 +1,78 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+use yew::Callback;
+
+/// What went wrong while translating a key.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum I18nEventKind {
+    /// The key doesn't exist in the translations of the language.
+    MissingKey,
+    /// The data injected in the template lacks a field.
+    MissingData,
+    /// The template couldn't be generated.
+    Template(String),
+    /// The handlebars template couldn't be rendered.
+    Handlebars(String),
+    /// The configuration of the provider is inconsistent, as checked by `I18nProviderProps::validate`.
+    InvalidConfiguration(String),
+}
+
+/// A problem encountered while translating a key, reported to the `I18nProvider` callbacks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct I18nEvent {
+    /// The language code in use.
+    pub language: String,
+    /// The missing key or data field, or the key of the template that failed.
+    pub key: String,
+    /// What went wrong.
+    pub kind: I18nEventKind,
+    /// The translation keys included (`{{{key}}}`) to reach the problem, starting from the key asked for.
+    pub include_chain: Vec<String>,
+}
+
+impl I18nEvent {
+    /// Whether the event is about a missing key or data field.
+    pub fn is_missing(&self) -> bool {
+        matches!(
+            self.kind,
+            I18nEventKind::MissingKey | I18nEventKind::MissingData
+        )
+    }
+}
+
+/// Forward the events to the `I18nProvider` callbacks, reporting each of them once per session.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct I18nReporter {
+    pub(crate) on_missing: Option<Callback<I18nEvent>>,
+    pub(crate) on_error: Option<Callback<I18nEvent>>,
+    pub(crate) reported: Rc<RefCell<HashSet<(String, String, I18nEventKind)>>>,
+}
+
+impl PartialEq for I18nReporter {
+    fn eq(&self, other: &Self) -> bool {
+        self.on_missing == other.on_missing && self.on_error == other.on_error
+    }
+}
+
+impl I18nReporter {
+    /// Send the event to its callback, unless it has already been reported.
+    pub(crate) fn report(&self, event: I18nEvent) {
+        let callback = if event.is_missing() {
+            &self.on_missing
+        } else {
+            &self.on_error
+        };
+        let Some(callback) = callback else {
+            return;
+        };
+        let first_time = self.reported.borrow_mut().insert((
+            event.language.clone(),
+            event.key.clone(),
+            event.kind.clone(),
+        ));
+        if first_time {
+            callback.emit(event);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use super::PseudoLocalization;
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use std::collections::HashMap;
+use unic_langid::LanguageIdentifier;
+
+/// Fluent (`.ftl`) resources parsed into one bundle per language.
+#[derive(Default)]
+pub struct FluentCatalog {
+    bundles: HashMap<String, FluentBundle<FluentResource>>,
+    errors: Vec<String>,
+    pseudo: Option<PseudoLocalization>,
+}
+
+impl std::fmt::Debug for FluentCatalog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FluentCatalog")
+            .field("languages", &self.bundles.keys().collect::<Vec<_>>())
+            .field("errors", &self.errors)
+            .finish()
+    }
+}
+
+impl FluentCatalog {
+    /// Parse the Fluent resources of every language, represented as a mapping from language codes to `.ftl` sources.
+    /// Invalid entries are skipped, their errors being kept in `errors`.
+    pub fn new(resources: &HashMap<String, Vec<String>>) -> Self {
+        let mut catalog = FluentCatalog::default();
+        for (language, sources) in resources {
+            let language_id = language.parse::<LanguageIdentifier>().unwrap_or_default();
+            let mut bundle = FluentBundle::new(vec![language_id]);
+            bundle.set_use_isolating(false);
+            for source in sources {
+                let resource = match FluentResource::try_new(source.clone()) {
+                    Ok(resource) => resource,
+                    Err((resource, errors)) => {
+                        catalog.errors.extend(
+                            errors
+                                .into_iter()
+                                .map(|err| format!("['{}'] {}", language, err)),
+                        );
+                        resource
+                    }
+                };
+                if let Err(errors) = bundle.add_resource(resource) {
+                    catalog.errors.extend(
+                        errors
+                            .into_iter()
+                            .map(|err| format!("['{}'] {}", language, err)),
+                    );
+                }
+            }
+            catalog.bundles.insert(language.clone(), bundle);
+        }
+        catalog
+    }
+
+    /// Format the messages of a pseudo-locale from the ones of its source language, unless it has its own resources.
+    /// The formatted messages are transformed, data included.
+    pub fn with_pseudo_localization(mut self, pseudo: Option<PseudoLocalization>) -> Self {
+        self.pseudo = pseudo;
+        self
+    }
+
+    /// The errors encountered while parsing the resources.
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+
+    /// Format the message (`message`) or attribute (`message.attribute`) of a language with some data,
+    /// the numeric values being given as numbers to the selectors.
+    /// Returns `None` when the message doesn't exist, or the formatted message with the errors encountered.
+    pub(crate) fn format(
+        &self,
+        language: &str,
+        key: &str,
+        data: Option<&HashMap<String, String>>,
+    ) -> Option<(String, Vec<String>)> {
+        let pseudo = self
+            .pseudo
+            .as_ref()
+            .filter(|pseudo| pseudo.language == language && !self.bundles.contains_key(language));
+        let bundle = self
+            .bundles
+            .get(pseudo.map_or(language, |pseudo| &pseudo.source_language))?;
+        let (id, attribute) = match key.split_once('.') {
+            Some((id, attribute)) => (id, Some(attribute)),
+            None => (key, None),
+        };
+        let message = bundle.get_message(id)?;
+        let pattern = match attribute {
+            Some(attribute) => message.get_attribute(attribute)?.value(),
+            None => message.value()?,
+        };
+        let args = data.map(|data| {
+            let mut args = FluentArgs::new();
+            for (name, value) in data {
+                match value.parse::<f64>() {
+                    Ok(number) => args.set(name.clone(), FluentValue::from(number)),
+                    Err(_) => args.set(name.clone(), FluentValue::from(value.clone())),
+                }
+            }
+            args
+        });
+        let mut errors = Vec::new();
+        let mut result = bundle
+            .format_pattern(pattern, args.as_ref(), &mut errors)
+            .to_string();
+        if let Some(pseudo) = pseudo {
+            result = pseudo.localize(&result);
+        }
+        Some((
+            result,
+            errors.into_iter().map(|err| err.to_string()).collect(),
+        ))
+    }
+}
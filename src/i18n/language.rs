@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+
+/// Primary language subtags written from right to left.
+const RTL_LANGUAGES: [&str; 12] = [
+    "ar", "arc", "ckb", "dv", "fa", "he", "iw", "ks", "ps", "sd", "ug", "ur",
+];
+
+/// The direction in which a language is written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TextDirection {
+    /// Left to right.
+    #[default]
+    Ltr,
+    /// Right to left.
+    Rtl,
+}
+
+impl TextDirection {
+    /// Guess the direction of a language from its tag (`ar`, `he-IL`, `fa_IR`, ...).
+    pub fn from_language_tag(tag: &str) -> Self {
+        let primary = tag
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        if RTL_LANGUAGES.contains(&primary.as_str()) {
+            TextDirection::Rtl
+        } else {
+            TextDirection::Ltr
+        }
+    }
+
+    /// The value of the `dir` HTML attribute for this direction.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TextDirection::Ltr => "ltr",
+            TextDirection::Rtl => "rtl",
+        }
+    }
+}
+
+/// Where the `I18nProvider` writes the `lang` and `dir` attributes of the current language.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LanguageAttributes {
+    /// The attributes are not written anywhere.
+    #[default]
+    None,
+    /// The children are rendered inside a `<div>` carrying the attributes.
+    Wrapper,
+    /// The attributes are synchronized onto the document root (`<html>`) whenever the language changes.
+    DocumentRoot,
+}
+
+/// Metadata describing a supported language.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LanguageInfo {
+    /// The language code used as a key of the translations (`en`, `fr`, `ar`, ...).
+    pub tag: String,
+    /// The name of the language in English.
+    pub english_name: String,
+    /// The name of the language in the language itself.
+    pub native_name: String,
+    /// The direction in which the language is written.
+    #[serde(default)]
+    pub direction: TextDirection,
+    /// An optional region associated to the language.
+    #[serde(default)]
+    pub region: Option<String>,
+    /// An optional flag (emoji, icon name, url, ...) associated to the language.
+    #[serde(default)]
+    pub flag: Option<String>,
+}
+
+impl LanguageInfo {
+    /// Create the metadata of a language, guessing its direction from its tag.
+    pub fn new(
+        tag: impl Into<String>,
+        english_name: impl Into<String>,
+        native_name: impl Into<String>,
+    ) -> Self {
+        let tag = tag.into();
+        Self {
+            direction: TextDirection::from_language_tag(&tag),
+            tag,
+            english_name: english_name.into(),
+            native_name: native_name.into(),
+            region: None,
+            flag: None,
+        }
+    }
+
+    /// Set the direction in which the language is written.
+    pub fn with_direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Set the region associated to the language.
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Set the flag associated to the language.
+    pub fn with_flag(mut self, flag: impl Into<String>) -> Self {
+        self.flag = Some(flag.into());
+        self
+    }
+}
+
+impl From<&str> for LanguageInfo {
+    /// Create the metadata of a language using its tag as its names.
+    fn from(tag: &str) -> Self {
+        Self::new(tag, tag, tag)
+    }
+}
+
+impl From<String> for LanguageInfo {
+    /// Create the metadata of a language using its tag as its names.
+    fn from(tag: String) -> Self {
+        Self::new(tag.clone(), tag.clone(), tag)
+    }
+}
+
+/// Parse an `Accept-Language` header (`fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5`) into its language tags,
+/// ordered by decreasing preference. The wildcard and the tags of quality `0` are left out.
+pub fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut languages: Vec<(String, f32)> = header
+        .split(',')
+        .filter_map(|item| {
+            let mut parts = item.split(';');
+            let tag = parts.next()?.trim();
+            let mut quality = 1.0;
+            for parameter in parts {
+                if let Some(value) = parameter.trim().strip_prefix("q=") {
+                    quality = value.trim().parse().ok()?;
+                }
+            }
+            (!tag.is_empty() && tag != "*" && quality > 0.0).then(|| (tag.to_string(), quality))
+        })
+        .collect();
+    languages.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    languages.into_iter().map(|(tag, _)| tag).collect()
+}
+
+/// Choose the supported language best matching the preferred ones, such as the languages of an
+/// `Accept-Language` header. A tag matches a supported language of the same tag or, failing that,
+/// of the same primary subtag (`fr-CA` matching `fr`).
+pub fn negotiate_language(preferred: &[String], supported: &[LanguageInfo]) -> Option<String> {
+    let normalize = |tag: &str| tag.replace('_', "-").to_lowercase();
+    let primary = |tag: &str| {
+        normalize(tag)
+            .split('-')
+            .next()
+            .unwrap_or_default()
+            .to_string()
+    };
+    for tag in preferred {
+        if let Some(language) = supported
+            .iter()
+            .find(|language| normalize(&language.tag) == normalize(tag))
+        {
+            return Some(language.tag.clone());
+        }
+        if let Some(language) = supported
+            .iter()
+            .find(|language| primary(&language.tag) == primary(tag))
+        {
+            return Some(language.tag.clone());
+        }
+    }
+    None
+}
@@ -0,0 +1,159 @@
+use super::{use_translation, I18nError, I18nHandler};
+use web_sys::HtmlSelectElement;
+use yew::{
+    function_component, html, AttrValue, Callback, Classes, Event, Html, Properties, TargetCast,
+};
+
+/// How the LanguageSelector component displays the supported languages.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LanguageSelectorVariant {
+    /// A `<select>` dropdown.
+    #[default]
+    Dropdown,
+    /// A group of buttons, the current language being pressed.
+    Buttons,
+}
+
+/// Configuration for the LanguageSelector component.
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct LanguageSelectorProps {
+    /// How the languages are displayed, a dropdown if not specified.
+    #[prop_or_default]
+    pub variant: LanguageSelectorVariant,
+    /// The accessible label of the selector.
+    #[prop_or(AttrValue::from("Language"))]
+    pub label: AttrValue,
+    /// Classes of the selector element.
+    #[prop_or_default]
+    pub class: Classes,
+    /// Called when the selected language can't be set.
+    #[prop_or_default]
+    pub on_error: Option<Callback<I18nError>>,
+}
+
+/// Set the language, forwarding the error to the callback if any.
+fn select_language(handler: &I18nHandler, on_error: &Option<Callback<I18nError>>, tag: String) {
+    if let Err(err) = handler.set_language(tag) {
+        if let Some(on_error) = on_error {
+            on_error.emit(err);
+        }
+    }
+}
+
+/// Yew component letting the user choose among the supported languages, displayed with their native name.
+#[function_component]
+pub fn LanguageSelector(props: &LanguageSelectorProps) -> Html {
+    let handler = use_translation();
+    let current_language = handler.current_language();
+    let languages = handler.supported_languages();
+
+    match props.variant {
+        LanguageSelectorVariant::Dropdown => {
+            let onchange = {
+                let handler = handler.clone();
+                let on_error = props.on_error.clone();
+                Callback::from(move |event: Event| {
+                    let select = event.target_unchecked_into::<HtmlSelectElement>();
+                    select_language(&handler, &on_error, select.value());
+                })
+            };
+            html!(
+                <select class={props.class.clone()} aria-label={props.label.clone()} {onchange}>
+                    { for languages.into_iter().map(|language| html!(
+                        <option
+                            value={language.tag.clone()}
+                            lang={language.tag.clone()}
+                            selected={language.tag == current_language}
+                        >
+                            { language.native_name }
+                        </option>
+                    )) }
+                </select>
+            )
+        }
+        LanguageSelectorVariant::Buttons => html!(
+            <div class={props.class.clone()} role="group" aria-label={props.label.clone()}>
+                { for languages.into_iter().map(|language| {
+                    let pressed = language.tag == current_language;
+                    let onclick = {
+                        let handler = handler.clone();
+                        let on_error = props.on_error.clone();
+                        let tag = language.tag.clone();
+                        Callback::from(move |_| select_language(&handler, &on_error, tag.clone()))
+                    };
+                    html!(
+                        <button
+                            type="button"
+                            lang={language.tag}
+                            aria-pressed={pressed.to_string()}
+                            {onclick}
+                        >
+                            { language.native_name }
+                        </button>
+                    )
+                }) }
+            </div>
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use yew::prelude::*;
+
+    use super::super::test_utils::render;
+    use super::{LanguageSelector, LanguageSelectorVariant};
+    use crate::{I18nProvider, LanguageInfo};
+
+    #[derive(Properties, PartialEq)]
+    struct AppProps {
+        variant: LanguageSelectorVariant,
+    }
+
+    #[function_component]
+    fn App(props: &AppProps) -> Html {
+        let supported_languages = vec![
+            LanguageInfo::new("en", "English", "English"),
+            LanguageInfo::new("fr", "French", "Français"),
+        ];
+        html! {
+            <I18nProvider {supported_languages} current_language="fr">
+                <LanguageSelector variant={props.variant} class="languages" />
+            </I18nProvider>
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dropdown_selector() {
+        let rendered = render::<App>(AppProps {
+            variant: LanguageSelectorVariant::Dropdown,
+        })
+        .await;
+        assert_eq!(
+            rendered,
+            concat!(
+                r#"<select aria-label="Language" class="languages">"#,
+                r#"<option lang="en" value="en">English</option>"#,
+                r#"<option lang="fr" value="fr" selected="selected">Français</option>"#,
+                "</select>"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_buttons_selector() {
+        let rendered = render::<App>(AppProps {
+            variant: LanguageSelectorVariant::Buttons,
+        })
+        .await;
+        assert_eq!(
+            rendered,
+            concat!(
+                r#"<div role="group" aria-label="Language" class="languages">"#,
+                r#"<button type="button" lang="en" aria-pressed="false">English</button>"#,
+                r#"<button type="button" lang="fr" aria-pressed="true">Français</button>"#,
+                "</div>"
+            )
+        );
+    }
+}
@@ -1,5 +1,34 @@
+mod errors;
+mod events;
+#[cfg(feature = "fluent")]
+mod fluent;
+mod language;
+mod language_selector;
 mod provider;
+mod pseudo;
+#[cfg(feature = "handlebars")]
+mod registry;
+#[cfg(test)]
+mod test_utils;
+#[cfg(feature = "translation_templater")]
+mod trans;
 mod use_translation;
 
-pub use provider::{I18nHandler, I18nProvider};
-pub use use_translation::use_translation;
+pub use errors::I18nError;
+pub use events::{I18nEvent, I18nEventKind};
+#[cfg(feature = "fluent")]
+pub use fluent::FluentCatalog;
+pub use language::{
+    negotiate_language, parse_accept_language, LanguageAttributes, LanguageInfo, TextDirection,
+};
+pub use language_selector::{LanguageSelector, LanguageSelectorProps, LanguageSelectorVariant};
+pub use provider::{
+    CatalogLayer, HydrationState, I18nHandler, I18nProvider, I18nProviderProps, KeySource,
+    ShowKeys, Translations, DEFAULT_NAMESPACE, HYDRATION_STATE_ID,
+};
+pub use pseudo::PseudoLocalization;
+#[cfg(feature = "handlebars")]
+pub use registry::HandlebarsRegistry;
+#[cfg(feature = "translation_templater")]
+pub use trans::{Trans, TransProps};
+pub use use_translation::{use_translation, use_translation_ns};
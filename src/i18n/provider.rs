@@ -1,119 +1,819 @@
 #[cfg(feature = "translation_templater")]
 use crate::templater::{
-    encode_json_to_hashmap, generate, parse_to_hashmap, StringTemplaterError,
-    StringTemplaterOptions,
+    encode_json_to_hashmap, generate, parse_to_hashmap, EscapeData, MissingFieldKind,
+    StringTemplaterError, StringTemplaterOptions, TranslationLookup,
 };
 #[cfg(feature = "handlebars")]
 use handlebars::{Handlebars, RenderError};
-use serde::Serialize;
-use std::collections::HashMap;
-use yew::{function_component, html, use_state, Callback, ContextProvider, Html, Properties};
 
-/// The I18nHandler struct representing the state and methods for internationalization.
+use super::events::I18nReporter;
+#[cfg(feature = "fluent")]
+use super::FluentCatalog;
+use super::{
+    negotiate_language, parse_accept_language, I18nError, I18nEvent, I18nEventKind,
+    LanguageAttributes, LanguageInfo, PseudoLocalization, TextDirection,
+};
+#[cfg(feature = "handlebars")]
+use super::{registry::I18nRegistry, HandlebarsRegistry};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use yew::{
+    function_component, html, use_effect_with, use_memo, use_mut_ref, use_reducer, use_state,
+    Callback, ContextProvider, Html, Properties, Reducible,
+};
+
+/// Flattened translations, represented as a mapping from language codes to their `key` -> `value` map.
+pub type Translations = HashMap<String, HashMap<String, String>>;
+
+/// The namespace of the `translations` prop, in which every other namespace falls back.
+pub const DEFAULT_NAMESPACE: &str = "common";
+
+/// The flattened translations of a language, seen from a namespace.
+struct NamespacedTranslations<'a> {
+    translations: &'a HashMap<String, String>,
+    namespace: Option<&'a str>,
+}
+
+impl NamespacedTranslations<'_> {
+    /// Find the value of a key, either prefixed by its namespace (`namespace:key`) or looked for in
+    /// the current namespace first, then in the default one.
+    fn get(&self, key: &str) -> Option<&String> {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    /// Find the stored key and the value of a key, looked for like in `get`.
+    fn get_key_value(&self, key: &str) -> Option<(&String, &String)> {
+        if let Some((namespace, relative_key)) = key.split_once(':') {
+            if namespace == DEFAULT_NAMESPACE {
+                return self.translations.get_key_value(relative_key);
+            }
+            return self.translations.get_key_value(key);
+        }
+        self.namespace
+            .filter(|namespace| *namespace != DEFAULT_NAMESPACE)
+            .and_then(|namespace| {
+                self.translations
+                    .get_key_value(&format!("{}:{}", namespace, key))
+            })
+            .or_else(|| self.translations.get_key_value(key))
+    }
+}
+
+#[cfg(feature = "translation_templater")]
+impl TranslationLookup for NamespacedTranslations<'_> {
+    fn lookup(&self, key: &str) -> Option<&String> {
+        self.get(key)
+    }
+}
+
+#[cfg(feature = "translation_templater")]
+/// Flatten the translations of every language, prefixing the keys of each namespace with `namespace:`.
+fn flatten_translations(
+    translations: &HashMap<String, serde_json::Value>,
+    namespaces: &HashMap<String, HashMap<String, serde_json::Value>>,
+) -> Translations {
+    let mut flattened: Translations = translations
+        .iter()
+        .map(|(key, value)| (key.clone(), encode_json_to_hashmap(value)))
+        .collect();
+    for (namespace, translations) in namespaces {
+        for (language, value) in translations {
+            let language_json = flattened.entry(language.clone()).or_default();
+            for (key, value) in encode_json_to_hashmap(value) {
+                if namespace == DEFAULT_NAMESPACE {
+                    language_json.insert(key, value);
+                } else {
+                    language_json.insert(format!("{}:{}", namespace, key), value);
+                }
+            }
+        }
+    }
+    flattened
+}
+
+/// Add the translations of the pseudo-locale, transformed from the ones of its source language.
+fn add_pseudo_translations(translations: &mut Translations, pseudo: &PseudoLocalization) {
+    let pseudo_translations = translations
+        .get(&pseudo.source_language)
+        .map(|source| {
+            source
+                .iter()
+                .map(|(key, template)| (key.clone(), pseudo.localize(template)))
+                .collect()
+        })
+        .unwrap_or_default();
+    translations.insert(pseudo.language.clone(), pseudo_translations);
+}
+
+/// Where the translations of the keys that don't come from the `translations` and `namespaces` props come from,
+/// represented as a mapping from language codes to their `key` -> source map.
+type KeySources = HashMap<String, HashMap<String, KeySource>>;
+
+/// Where the translation of a key comes from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeySource {
+    /// The `translations` or `namespaces` props.
+    Translations,
+    /// A layer of the `layers` prop, by name.
+    Layer(String),
+    /// A runtime update, such as `I18nHandler::merge_translations`.
+    Runtime,
+    #[cfg(feature = "fluent")]
+    /// The Fluent resources.
+    Fluent,
+}
+
+/// Translations overriding the ones of the layers below, such as the strings of a tenant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CatalogLayer {
+    /// The name of the layer, such as `tenant`.
+    pub name: String,
+    /// Translations for different languages, represented like the `translations` prop.
+    pub translations: HashMap<String, serde_json::Value>,
+}
+
+impl CatalogLayer {
+    /// Create a new layer from its name and translations.
+    pub fn new(name: impl Into<String>, translations: HashMap<String, serde_json::Value>) -> Self {
+        Self {
+            name: name.into(),
+            translations,
+        }
+    }
+}
+
+/// The flattened catalogs of the props, from the bottom to the top one.
+#[derive(Debug, Default)]
+struct CatalogLayers(Vec<(KeySource, Translations)>);
+
+/// Compared by identity, as the layers are shared behind an `Rc` between the renders of the provider.
+impl PartialEq for CatalogLayers {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl CatalogLayers {
+    /// Flatten the `translations` and `namespaces` props, then each layer of the `layers` prop.
+    fn new(
+        translations: &HashMap<String, serde_json::Value>,
+        namespaces: &HashMap<String, HashMap<String, serde_json::Value>>,
+        layers: &[CatalogLayer],
+    ) -> Self {
+        let mut flattened = vec![(
+            KeySource::Translations,
+            flatten_translations(translations, namespaces),
+        )];
+        flattened.extend(layers.iter().map(|layer| {
+            let translations = layer
+                .translations
+                .iter()
+                .map(|(language, value)| (language.clone(), flatten_catalog(value)))
+                .collect();
+            (KeySource::Layer(layer.name.clone()), translations)
+        }));
+        Self(flattened)
+    }
+
+    /// Whether any layer has translations for `language`.
+    fn has_language(&self, language: &str) -> bool {
+        self.0
+            .iter()
+            .any(|(_, translations)| translations.contains_key(language))
+    }
+
+    /// Apply the runtime changes on top of the layers, then add the translations of the pseudo-locale, if any.
+    /// A key is translated by its topmost layer, skipping the ones from which it was removed.
+    fn compose(
+        &self,
+        runtime: &RuntimeTranslations,
+        pseudo: Option<&PseudoLocalization>,
+    ) -> (Translations, KeySources) {
+        let mut translations = Translations::new();
+        let mut sources = KeySources::new();
+        let languages: HashSet<&String> = self
+            .0
+            .iter()
+            .flat_map(|(_, translations)| translations.keys())
+            .filter(|language| !runtime.replaced.contains(*language))
+            .collect();
+        for language in languages {
+            let catalogs: Vec<_> = self
+                .0
+                .iter()
+                .rev()
+                .filter_map(|(source, translations)| Some((source, translations.get(language)?)))
+                .collect();
+            let hidden = runtime.hidden.get(language);
+            let language_translations = translations.entry(language.clone()).or_default();
+            let language_sources = sources.entry(language.clone()).or_default();
+            for key in catalogs.iter().flat_map(|(_, catalog)| catalog.keys()) {
+                if language_translations.contains_key(key) {
+                    continue;
+                }
+                let skipped = hidden.and_then(|hidden| hidden.get(key)).copied();
+                let found = catalogs
+                    .iter()
+                    .filter_map(|(source, catalog)| Some((*source, catalog.get(key)?)))
+                    .nth(skipped.unwrap_or_default());
+                if let Some((source, value)) = found {
+                    language_translations.insert(key.clone(), value.clone());
+                    if *source != KeySource::Translations {
+                        language_sources.insert(key.clone(), source.clone());
+                    }
+                }
+            }
+        }
+        for (language, catalog) in &runtime.overrides {
+            translations.entry(language.clone()).or_default().extend(
+                catalog
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.clone())),
+            );
+            sources
+                .entry(language.clone())
+                .or_default()
+                .extend(catalog.keys().map(|key| (key.clone(), KeySource::Runtime)));
+        }
+        if let Some(pseudo) = pseudo {
+            add_pseudo_translations(&mut translations, pseudo);
+            let pseudo_sources = sources
+                .get(&pseudo.source_language)
+                .cloned()
+                .unwrap_or_default();
+            sources.insert(pseudo.language.clone(), pseudo_sources);
+        }
+        (translations, sources)
+    }
+}
+
+/// A change of the translations of a language, applied by the provider at runtime.
 #[derive(Clone, Debug, PartialEq)]
+enum TranslationsUpdate {
+    /// Insert the flattened translations, overriding the existing keys.
+    Merge(String, HashMap<String, String>),
+    /// Replace every translation of the language.
+    Replace(String, HashMap<String, String>),
+    /// Remove a key.
+    RemoveKey(String, String),
+}
+
+/// The changes made to the translations at runtime, applied on top of the layers of the props.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct RuntimeTranslations {
+    /// Incremented on every change, telling the provider to compose the translations again.
+    version: usize,
+    /// The translations merged or replaced at runtime.
+    overrides: Translations,
+    /// How many of the topmost layers translating a key are skipped, for each language.
+    hidden: HashMap<String, HashMap<String, usize>>,
+    /// The languages whose layers are replaced by the runtime translations.
+    replaced: HashSet<String>,
+}
+
+impl Reducible for RuntimeTranslations {
+    type Action = TranslationsUpdate;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut runtime = (*self).clone();
+        runtime.version += 1;
+        match action {
+            TranslationsUpdate::Merge(language, catalog) => {
+                runtime
+                    .overrides
+                    .entry(language)
+                    .or_default()
+                    .extend(catalog);
+            }
+            TranslationsUpdate::Replace(language, catalog) => {
+                runtime.hidden.remove(&language);
+                runtime.replaced.insert(language.clone());
+                runtime.overrides.insert(language, catalog);
+            }
+            TranslationsUpdate::RemoveKey(language, key) => {
+                let removed = runtime
+                    .overrides
+                    .get_mut(&language)
+                    .and_then(|catalog| catalog.remove(&key));
+                if removed.is_none() && !runtime.replaced.contains(&language) {
+                    *runtime
+                        .hidden
+                        .entry(language)
+                        .or_default()
+                        .entry(key)
+                        .or_default() += 1;
+                }
+            }
+        }
+        Rc::new(runtime)
+    }
+}
+
+/// Flatten a catalog like the `translations` prop, keeping the keys of the default namespace unprefixed.
+fn flatten_catalog(translations: &serde_json::Value) -> HashMap<String, String> {
+    encode_json_to_hashmap(translations)
+        .into_iter()
+        .map(|(key, value)| (catalog_key(&key).to_string(), value))
+        .collect()
+}
+
+/// The key under which a translation is stored, without the prefix of the default namespace.
+fn catalog_key(key: &str) -> &str {
+    key.strip_prefix(DEFAULT_NAMESPACE)
+        .and_then(|key| key.strip_prefix(':'))
+        .unwrap_or(key)
+}
+
+/// What the translation methods display, helping to map a screenshot to the keys of the catalogs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ShowKeys {
+    /// The translations.
+    #[default]
+    Off,
+    /// The keys instead of the translations.
+    Keys,
+    /// The keys followed by the current language, as `key [en]`.
+    KeysWithLanguage,
+}
+
+/// What the provider writes into the rendered page, so that hydrating picks up the state chosen on the server.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HydrationState {
+    /// Nothing.
+    #[default]
+    Off,
+    /// The current language.
+    Language,
+    /// The current language and its translations, filling them in when the client lacks that language.
+    LanguageAndCatalog,
+}
+
+/// The `id` of the `<script>` element holding the state written by the provider.
+pub const HYDRATION_STATE_ID: &str = "yew-translator-state";
+
+/// The state written into the rendered page by the provider.
+#[derive(Debug, Serialize, Deserialize)]
+struct HydrationData {
+    language: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    translations: Option<HashMap<String, String>>,
+}
+
+impl HydrationData {
+    /// Serialize the state as the content of a `<script>` element, escaping what could end it early.
+    fn to_script(&self) -> String {
+        serde_json::to_string(self)
+            .unwrap_or_default()
+            .replace('<', "\\u003c")
+            .replace('>', "\\u003e")
+            .replace('&', "\\u0026")
+    }
+
+    /// Read the state written into the page, when hydrating in a browser.
+    fn read() -> Option<Self> {
+        if !cfg!(target_arch = "wasm32") {
+            return None;
+        }
+        let content = web_sys::window()?
+            .document()?
+            .get_element_by_id(HYDRATION_STATE_ID)?
+            .text_content()?;
+        serde_json::from_str(&content).ok()
+    }
+}
+
+/// The I18nHandler struct representing the state and methods for internationalization.
+#[derive(Clone, Debug)]
 pub struct I18nHandler {
     /// The current language code for translations.
     current_language: String,
     /// List of supported languages.
-    supported_languages: Vec<&'static str>,
+    supported_languages: Rc<Vec<LanguageInfo>>,
     /// Set the current language code for translations.
     set_language: Callback<String>,
-    /// Translations for different languages, represented as a mapping from language codes to JSON values.
-    translations: HashMap<String, HashMap<String, String>>,
+    /// What the translation methods display.
+    show_keys: ShowKeys,
+    /// Set what the translation methods display.
+    set_show_keys: Callback<ShowKeys>,
+    /// Change the translations of the provider.
+    update_translations: Callback<TranslationsUpdate>,
+    /// Flattened translations for different languages, shared between every render of the provider.
+    translations: Rc<Translations>,
+    /// Where the translations that don't come from the props come from.
+    sources: Rc<KeySources>,
+    /// The namespace in which the unprefixed keys are looked for first.
+    namespace: Option<String>,
+    /// The prefix of the keys relative to the handler.
+    scope: Option<String>,
+    /// Report the missing keys and errors to the provider callbacks.
+    reporter: I18nReporter,
+    #[cfg(feature = "fluent")]
+    /// Fluent messages, looked for before the translations.
+    fluent: Rc<FluentCatalog>,
+    #[cfg(feature = "handlebars")]
+    /// The registry rendering the handlebars templates, a new empty one on every call if not provided.
+    handlebars: Option<Rc<I18nRegistry>>,
+}
+
+impl PartialEq for I18nHandler {
+    fn eq(&self, other: &Self) -> bool {
+        self.current_language == other.current_language
+            && self.supported_languages == other.supported_languages
+            && self.set_language == other.set_language
+            && self.show_keys == other.show_keys
+            && self.set_show_keys == other.set_show_keys
+            && self.update_translations == other.update_translations
+            && Rc::ptr_eq(&self.translations, &other.translations)
+            && Rc::ptr_eq(&self.sources, &other.sources)
+            && self.namespace == other.namespace
+            && self.scope == other.scope
+            && self.reporter == other.reporter
+            && self.fluent_eq(other)
+            && self.handlebars_eq(other)
+    }
 }
 
 impl I18nHandler {
+    #[cfg(feature = "fluent")]
+    fn fluent_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.fluent, &other.fluent)
+    }
+
+    #[cfg(not(feature = "fluent"))]
+    fn fluent_eq(&self, _other: &Self) -> bool {
+        true
+    }
+
+    #[cfg(feature = "handlebars")]
+    fn handlebars_eq(&self, other: &Self) -> bool {
+        match (&self.handlebars, &other.handlebars) {
+            (Some(registry), Some(other)) => Rc::ptr_eq(registry, other),
+            (registry, other) => registry.is_none() && other.is_none(),
+        }
+    }
+
+    #[cfg(not(feature = "handlebars"))]
+    fn handlebars_eq(&self, _other: &Self) -> bool {
+        true
+    }
+
+    #[cfg(feature = "fluent")]
+    /// Format the Fluent message of a resolved key, if it exists, failing on any formatting error.
+    fn try_fluent(
+        &self,
+        key: &str,
+        data: Option<&HashMap<String, String>>,
+    ) -> Option<Result<String, I18nError>> {
+        let (result, errors) = self.fluent.format(&self.current_language, key, data)?;
+        if errors.is_empty() {
+            Some(Ok(result))
+        } else {
+            Some(Err(I18nError::Fluent(errors.join(", "))))
+        }
+    }
+
+    #[cfg(feature = "fluent")]
+    /// Format the Fluent message of a resolved key to display it, if it exists, reporting its formatting errors.
+    fn fluent_display(&self, key: &str, data: Option<&HashMap<String, String>>) -> Option<String> {
+        let (result, errors) = self.fluent.format(&self.current_language, key, data)?;
+        for err in errors {
+            self.report(key, I18nEventKind::Template(err), vec![key.to_string()]);
+        }
+        Some(result)
+    }
+
     /// Get the current language code for translations.
     pub fn current_language(&self) -> String {
         self.current_language.clone()
     }
 
-    /// Get the supported languages codes for translations.
-    pub fn supported_languages(&self) -> Vec<&'static str> {
-        self.supported_languages.clone()
+    /// Get the metadata of the current language, if it's one of the supported languages.
+    pub fn current_language_info(&self) -> Option<LanguageInfo> {
+        self.supported_languages
+            .iter()
+            .find(|language| language.tag == self.current_language)
+            .cloned()
+    }
+
+    /// Get the direction in which the current language is written.
+    pub fn direction(&self) -> TextDirection {
+        self.current_language_info()
+            .map(|language| language.direction)
+            .unwrap_or_else(|| TextDirection::from_language_tag(&self.current_language))
+    }
+
+    /// Get the supported languages for translations.
+    pub fn supported_languages(&self) -> Vec<LanguageInfo> {
+        self.supported_languages.to_vec()
     }
 
     /// Set the current language code for translations.
-    pub fn set_language(&self, language: impl Into<String>) -> Result<(), String> {
+    pub fn set_language(&self, language: impl Into<String>) -> Result<(), I18nError> {
         let language = language.into();
-        if self.supported_languages.contains(&language.as_str()) {
+        if self
+            .supported_languages
+            .iter()
+            .any(|supported| supported.tag == language)
+        {
             self.set_language.emit(language);
             Ok(())
         } else {
-            Err(format!("The language `{}` is not available", language))
+            Err(I18nError::UnsupportedLanguage(language))
         }
     }
 
-    // Find the value to display for the current language code in use.
-    pub fn t(&self, key: &str) -> String {
+    /// Get what the translation methods display.
+    pub fn show_keys(&self) -> ShowKeys {
+        self.show_keys
+    }
+
+    /// Set what the translation methods display, such as the keys instead of the translations.
+    pub fn set_show_keys(&self, show_keys: ShowKeys) {
+        self.set_show_keys.emit(show_keys);
+    }
+
+    /// Merge translations into the ones of `language`, overriding the existing keys, and re-render the consumers.
+    /// The translations are flattened like the `translations` prop, and their keys are looked for in another
+    /// namespace when prefixed with `namespace:`, such as `{ "admin:menu": { "title": "Admin" } }`.
+    /// The Fluent messages, looked for first, aren't changed by the runtime updates.
+    pub fn merge_translations(
+        &self,
+        language: impl Into<String>,
+        translations: &serde_json::Value,
+    ) {
+        self.update_translations.emit(TranslationsUpdate::Merge(
+            language.into(),
+            flatten_catalog(translations),
+        ));
+    }
+
+    /// Replace every translation of `language`, in every namespace, and re-render the consumers.
+    pub fn replace_translations(
+        &self,
+        language: impl Into<String>,
+        translations: &serde_json::Value,
+    ) {
+        self.update_translations.emit(TranslationsUpdate::Replace(
+            language.into(),
+            flatten_catalog(translations),
+        ));
+    }
+
+    /// Remove the translation of a full key (`namespace:key` in another namespace) in `language`,
+    /// and re-render the consumers.
+    pub fn remove_key(&self, language: impl Into<String>, key: &str) {
+        self.update_translations.emit(TranslationsUpdate::RemoveKey(
+            language.into(),
+            catalog_key(key).to_string(),
+        ));
+    }
+
+    /// Find where the translation of a key in the current language comes from, if it exists.
+    pub fn key_source(&self, key: &str) -> Option<KeySource> {
+        let key = &*self.resolve_key(key);
+        #[cfg(feature = "fluent")]
+        if self
+            .fluent
+            .format(&self.current_language, key, None)
+            .is_some()
+        {
+            return Some(KeySource::Fluent);
+        }
+        let translations = self.language_translations(key)?;
+        let (stored_key, _) = translations.get_key_value(key)?;
+        Some(
+            self.sources
+                .get(&self.current_language)
+                .and_then(|sources| sources.get(stored_key))
+                .cloned()
+                .unwrap_or(KeySource::Translations),
+        )
+    }
+
+    /// The key to display instead of the translation of `key`, if the keys are shown.
+    pub(crate) fn shown_key(&self, key: &str) -> Option<String> {
+        match self.show_keys {
+            ShowKeys::Off => None,
+            ShowKeys::Keys => Some(self.resolve_key(key).into_owned()),
+            ShowKeys::KeysWithLanguage => Some(format!(
+                "{} [{}]",
+                self.resolve_key(key),
+                self.current_language
+            )),
+        }
+    }
+
+    /// Get the namespace in which the unprefixed keys are looked for first.
+    pub fn namespace(&self) -> Option<String> {
+        self.namespace.clone()
+    }
+
+    /// Get a handler looking the unprefixed keys up in `namespace` first, then in the default namespace.
+    pub fn namespaced(&self, namespace: impl Into<String>) -> I18nHandler {
+        I18nHandler {
+            namespace: Some(namespace.into()),
+            ..self.clone()
+        }
+    }
+
+    /// Get a handler translating in `language` instead of the current language, without changing it.
+    pub fn in_language(&self, language: impl Into<String>) -> I18nHandler {
+        I18nHandler {
+            current_language: language.into(),
+            ..self.clone()
+        }
+    }
+
+    /// Get the prefix of the keys relative to the handler.
+    pub fn scope(&self) -> Option<String> {
+        self.scope.clone()
+    }
+
+    /// Get a handler resolving the keys relative to `prefix` (nested in the scope of this handler, if any).
+    /// Keys starting with `~` are still resolved from the root, such as `~common.ok`.
+    pub fn scoped(&self, prefix: &str) -> I18nHandler {
+        let prefix = prefix.trim_end_matches('.');
+        I18nHandler {
+            scope: Some(match &self.scope {
+                Some(scope) => format!("{}.{}", scope, prefix),
+                None => prefix.to_string(),
+            }),
+            ..self.clone()
+        }
+    }
+
+    /// Resolve a key relative to the scope of the handler, unless it starts with `~`.
+    fn resolve_key<'a>(&self, key: &'a str) -> Cow<'a, str> {
+        if let Some(absolute_key) = key.strip_prefix('~') {
+            return Cow::Borrowed(absolute_key);
+        }
+        match (&self.scope, key.split_once(':')) {
+            (None, _) => Cow::Borrowed(key),
+            (Some(scope), Some((namespace, relative_key))) => {
+                Cow::Owned(format!("{}:{}.{}", namespace, scope, relative_key))
+            }
+            (Some(scope), None) => Cow::Owned(format!("{}.{}", scope, key)),
+        }
+    }
+
+    /// The translations of the current language, seen from the namespace of a resolved key:
+    /// its `namespace:` prefix if any, the namespace of the handler otherwise.
+    fn language_translations<'a>(&'a self, key: &'a str) -> Option<NamespacedTranslations<'a>> {
+        let namespace = match key.split_once(':') {
+            Some((namespace, _)) => Some(namespace),
+            None => self.namespace.as_deref(),
+        };
         self.translations
             .get(&self.current_language)
-            .and_then(|language_json| language_json.get(key))
-            .map_or_else(
-                || {
-                    format!(
-                        "['{}'](T - '{}')",
-                        self.current_language.to_uppercase(),
-                        key
-                    )
-                },
-                |s| s.clone(),
+            .map(|translations| NamespacedTranslations {
+                translations,
+                namespace,
+            })
+    }
+
+    /// Find the value for the current language code in use, or the reason why it can't be found.
+    pub fn try_t(&self, key: &str) -> Result<String, I18nError> {
+        let key = &*self.resolve_key(key);
+        #[cfg(feature = "fluent")]
+        if let Some(result) = self.try_fluent(key, None) {
+            return result;
+        }
+        self.language_translations(key)
+            .and_then(|language_json| language_json.get(key).cloned())
+            .ok_or_else(|| self.missing_key(key))
+    }
+
+    /// The error of a key missing from the current language.
+    fn missing_key(&self, key: &str) -> I18nError {
+        I18nError::MissingKey {
+            language: self.current_language.clone(),
+            key: key.to_string(),
+        }
+    }
+
+    /// Report an event about a key of the current language to the provider callbacks.
+    fn report(&self, key: &str, kind: I18nEventKind, include_chain: Vec<String>) {
+        self.reporter.report(I18nEvent {
+            language: self.current_language.clone(),
+            key: key.to_string(),
+            kind,
+            include_chain,
+        });
+    }
+
+    /// Report an error returned while translating `key` to the provider callbacks.
+    pub(crate) fn report_error(&self, key: &str, err: &I18nError) {
+        let key = &*self.resolve_key(key);
+        match err {
+            I18nError::MissingKey { key, .. } => {
+                self.report(key, I18nEventKind::MissingKey, Vec::new())
+            }
+            I18nError::MissingData { key: field, .. } => {
+                self.report(field, I18nEventKind::MissingData, vec![key.to_string()])
+            }
+            #[cfg(feature = "translation_templater")]
+            I18nError::Template(err) => self.report(
+                key,
+                I18nEventKind::Template(err.to_string()),
+                vec![key.to_string()],
+            ),
+            #[cfg(feature = "handlebars")]
+            I18nError::Handlebars(err) => self.report(
+                key,
+                I18nEventKind::Handlebars(err.to_string()),
+                vec![key.to_string()],
+            ),
+            #[cfg(feature = "fluent")]
+            I18nError::Fluent(err) => self.report(
+                key,
+                I18nEventKind::Template(err.clone()),
+                vec![key.to_string()],
+            ),
+            I18nError::InvalidConfiguration(message) => self.report(
+                key,
+                I18nEventKind::InvalidConfiguration(message.clone()),
+                Vec::new(),
+            ),
+            I18nError::UnsupportedLanguage(_) => {}
+        }
+    }
+
+    // Find the value to display for the current language code in use.
+    pub fn t(&self, key: &str) -> String {
+        if let Some(key) = self.shown_key(key) {
+            return key;
+        }
+        #[cfg(feature = "fluent")]
+        if let Some(result) = self.fluent_display(&self.resolve_key(key), None) {
+            return result;
+        }
+        self.try_t(key).unwrap_or_else(|_| {
+            let key = &*self.resolve_key(key);
+            self.report(key, I18nEventKind::MissingKey, Vec::new());
+            format!(
+                "['{}'](T - '{}')",
+                self.current_language.to_uppercase(),
+                key
             )
+        })
+    }
+
+    #[cfg(feature = "translation_templater")]
+    /// The options used to display (and report) the missing data and translations in the template of `key`.
+    fn display_options(&self, key: &str) -> StringTemplaterOptions {
+        StringTemplaterOptions {
+            safe_parse: true,
+            display_missing_keys: true,
+            override_missing_keys: {
+                let current_language = self.current_language.clone();
+                Some(Box::new(move |key| {
+                    format!("['{}'](D - '{}')", current_language.to_uppercase(), key)
+                }))
+            },
+            display_missing_translations: true,
+            override_missing_translations: {
+                let current_language = self.current_language.clone();
+                Some(Box::new(move |key| {
+                    format!("['{}'](T - '{}')", current_language.to_uppercase(), key)
+                }))
+            },
+            report_missing: {
+                let reporter = self.reporter.clone();
+                let language = self.current_language.clone();
+                let key = key.to_string();
+                Some(Box::new(move |missing| {
+                    let kind = match missing.kind {
+                        MissingFieldKind::Data => I18nEventKind::MissingData,
+                        MissingFieldKind::Translation => I18nEventKind::MissingKey,
+                    };
+                    let mut include_chain = vec![key.clone()];
+                    include_chain.extend(missing.include_chain.iter().cloned());
+                    reporter.report(I18nEvent {
+                        language: language.clone(),
+                        key: missing.key.clone(),
+                        kind,
+                        include_chain,
+                    });
+                }))
+            },
+            escape_data: None,
+        }
     }
 
     #[cfg(feature = "translation_templater")]
     // Find the template to display for the current language code in use and inject it some data (Use the translation_templater).
     pub fn tt<T: ?Sized + Serialize>(&self, key: &str, data: &T) -> String {
-        match parse_to_hashmap(data) {
-            Ok(data) => self.tth_with_options(
-                key,
-                &data,
-                &StringTemplaterOptions {
-                    safe_parse: true,
-                    display_missing_keys: true,
-                    override_missing_keys: {
-                        let current_language = self.current_language.clone();
-                        Some(Box::new(move |key| {
-                            format!("['{}'](D - '{}')", current_language.to_uppercase(), key)
-                        }))
-                    },
-                    display_missing_translations: true,
-                    override_missing_translations: {
-                        let current_language = self.current_language.clone();
-                        Some(Box::new(move |key| {
-                            format!("['{}'](T - '{}')", current_language.to_uppercase(), key)
-                        }))
-                    },
-                },
-            ),
-            Err(err) => err.to_string(),
-        }
+        self.tt_with_options(key, data, &self.display_options(&self.resolve_key(key)))
     }
 
     #[cfg(feature = "translation_templater")]
     // Find the template to display for the current language code in use and inject it some data (Use the translation_templater).
     pub fn tth(&self, key: &str, data: &HashMap<String, String>) -> String {
-        self.tth_with_options(
-            key,
-            data,
-            &StringTemplaterOptions {
-                safe_parse: true,
-                display_missing_keys: true,
-                override_missing_keys: {
-                    let current_language = self.current_language.clone();
-                    Some(Box::new(move |key| {
-                        format!("['{}'](D - '{}')", current_language.to_uppercase(), key)
-                    }))
-                },
-                display_missing_translations: true,
-                override_missing_translations: {
-                    let current_language = self.current_language.clone();
-                    Some(Box::new(move |key| {
-                        format!("['{}'](T - '{}')", current_language.to_uppercase(), key)
-                    }))
-                },
-            },
-        )
+        self.tth_with_options(key, data, &self.display_options(&self.resolve_key(key)))
     }
 
     #[cfg(feature = "translation_templater")]
@@ -124,15 +824,33 @@ impl I18nHandler {
         data: &HashMap<String, String>,
         option: &StringTemplaterOptions,
     ) -> String {
-        self.translations
-            .get(&self.current_language)
+        if let Some(key) = self.shown_key(key) {
+            return key;
+        }
+        let key = &*self.resolve_key(key);
+        #[cfg(feature = "fluent")]
+        if let Some(result) = self.fluent_display(key, Some(data)) {
+            return result;
+        }
+        self.language_translations(key)
             .map(|language_json| {
                 let result = match language_json.get(key) {
-                    Some(template_string) => generate(template_string, language_json, data, option),
-                    None => Err(StringTemplaterError::UnknownField(format!(
-                        "The field `{}` does not exist in the hashmap.",
-                        key
-                    ))),
+                    Some(template_string) => {
+                        generate(template_string, &language_json, data, option).inspect_err(|err| {
+                            self.report(
+                                key,
+                                I18nEventKind::Template(err.to_string()),
+                                vec![key.to_string()],
+                            )
+                        })
+                    }
+                    None => {
+                        self.report(key, I18nEventKind::MissingKey, Vec::new());
+                        Err(StringTemplaterError::UnknownField(format!(
+                            "The field `{}` does not exist in the hashmap.",
+                            key
+                        )))
+                    }
                 };
                 match result {
                     Ok(r) => r,
@@ -141,6 +859,7 @@ impl I18nHandler {
             })
             .map_or_else(
                 || {
+                    self.report(key, I18nEventKind::MissingKey, Vec::new());
                     format!(
                         "['{}'](T - '{}')",
                         self.current_language.to_uppercase(),
@@ -159,9 +878,94 @@ impl I18nHandler {
         data: &T,
         option: &StringTemplaterOptions,
     ) -> String {
+        if let Some(key) = self.shown_key(key) {
+            return key;
+        }
         match parse_to_hashmap(data) {
             Ok(data) => self.tth_with_options(key, &data, option),
-            Err(err) => err.to_string(),
+            Err(err) => {
+                let message = err.to_string();
+                let key = &*self.resolve_key(key);
+                self.report(
+                    key,
+                    I18nEventKind::Template(message.clone()),
+                    vec![key.to_string()],
+                );
+                message
+            }
+        }
+    }
+
+    #[cfg(feature = "translation_templater")]
+    /// Find the template for the current language code in use and inject it some data, failing on any missing key or data (Use the translation_templater).
+    pub fn try_tt<T: ?Sized + Serialize>(&self, key: &str, data: &T) -> Result<String, I18nError> {
+        self.try_tth(key, &parse_to_hashmap(data)?)
+    }
+
+    #[cfg(feature = "translation_templater")]
+    /// Find the template for the current language code in use and inject it some data, failing on any missing key or data (Use the translation_templater).
+    pub fn try_tth(&self, key: &str, data: &HashMap<String, String>) -> Result<String, I18nError> {
+        self.try_tth_escaped(key, data, None)
+    }
+
+    #[cfg(feature = "translation_templater")]
+    /// Like `try_tth`, transforming the data values injected with `{{field}}` with `escape_data`.
+    pub(crate) fn try_tth_escaped(
+        &self,
+        key: &str,
+        data: &HashMap<String, String>,
+        escape_data: Option<EscapeData>,
+    ) -> Result<String, I18nError> {
+        #[cfg(feature = "fluent")]
+        {
+            // The Fluent variables are all data, so they are escaped before being formatted.
+            let escaped: HashMap<String, String>;
+            let fluent_data = match &escape_data {
+                Some(escape_data) => {
+                    escaped = data
+                        .iter()
+                        .map(|(field, value)| (field.clone(), escape_data(value)))
+                        .collect();
+                    &escaped
+                }
+                None => data,
+            };
+            if let Some(result) = self.try_fluent(&self.resolve_key(key), Some(fluent_data)) {
+                return result;
+            }
+        }
+        let template = self.try_t(key)?;
+        let resolved_key = self.resolve_key(key);
+        let Some(language_json) = self.language_translations(&resolved_key) else {
+            return Err(self.missing_key(&resolved_key));
+        };
+        let missing: Rc<RefCell<Option<I18nError>>> = Rc::new(RefCell::new(None));
+        let option = StringTemplaterOptions {
+            safe_parse: true,
+            report_missing: {
+                let missing = missing.clone();
+                let language = self.current_language.clone();
+                Some(Box::new(move |field| {
+                    missing.borrow_mut().get_or_insert_with(|| {
+                        let language = language.clone();
+                        let key = field.key.clone();
+                        match field.kind {
+                            MissingFieldKind::Data => I18nError::MissingData { language, key },
+                            MissingFieldKind::Translation => {
+                                I18nError::MissingKey { language, key }
+                            }
+                        }
+                    });
+                }))
+            },
+            escape_data,
+            ..Default::default()
+        };
+        let result = generate(&template, &language_json, data, &option)?;
+        let missing = missing.borrow_mut().take();
+        match missing {
+            Some(err) => Err(err),
+            None => Ok(result),
         }
     }
 
@@ -169,18 +973,65 @@ impl I18nHandler {
     // Using your own instance of Handlebars, find the template to display for the current language code in use and inject it some data (Use handlebars).
     pub fn thb_registry<T: Serialize>(
         &self,
-        reg: Handlebars,
+        reg: &Handlebars,
         key: &str,
         data: &T,
     ) -> Result<String, RenderError> {
-        reg.render_template(&self.t(key), data)
+        if let Some(key) = self.shown_key(key) {
+            return Ok(key);
+        }
+        self.report_handlebars(
+            &self.resolve_key(key),
+            reg.render_template(&self.t(key), data),
+        )
     }
 
     #[cfg(feature = "handlebars")]
     // Find the template to display for the current language code in use and inject it some data (Use handlebars).
     pub fn thb<T: Serialize>(&self, key: &str, data: &T) -> Result<String, RenderError> {
-        let reg = Handlebars::new();
-        reg.render_template(&self.t(key), data)
+        if let Some(key) = self.shown_key(key) {
+            return Ok(key);
+        }
+        self.report_handlebars(
+            &self.resolve_key(key),
+            self.render_handlebars(&self.t(key), data),
+        )
+    }
+
+    #[cfg(feature = "handlebars")]
+    /// Render a handlebars template with the registry of the provider, if any.
+    fn render_handlebars<T: Serialize>(
+        &self,
+        template: &str,
+        data: &T,
+    ) -> Result<String, RenderError> {
+        match &self.handlebars {
+            Some(registry) => registry.render(self, template, data),
+            None => Handlebars::new().render_template(template, data),
+        }
+    }
+
+    #[cfg(feature = "handlebars")]
+    /// Report the rendering error of a handlebars template, if any.
+    fn report_handlebars(
+        &self,
+        key: &str,
+        result: Result<String, RenderError>,
+    ) -> Result<String, RenderError> {
+        if let Err(err) = &result {
+            self.report(
+                key,
+                I18nEventKind::Handlebars(err.to_string()),
+                vec![key.to_string()],
+            );
+        }
+        result
+    }
+
+    #[cfg(feature = "handlebars")]
+    /// Find the template for the current language code in use and inject it some data, failing on a missing key (Use handlebars).
+    pub fn try_thb<T: Serialize>(&self, key: &str, data: &T) -> Result<String, I18nError> {
+        Ok(self.render_handlebars(&self.try_t(key)?, data)?)
     }
 }
 
@@ -188,50 +1039,300 @@ impl I18nHandler {
 #[derive(Debug, Clone, PartialEq, Properties)]
 pub struct I18nProviderProps {
     /// List of supported languages. Defaults to English and French if not specified.
-    #[prop_or_else(|| vec!["en", "fr"])]
-    pub supported_languages: Vec<&'static str>,
+    #[prop_or_else(|| vec![LanguageInfo::new("en", "English", "English"), LanguageInfo::new("fr", "French", "Français")])]
+    pub supported_languages: Vec<LanguageInfo>,
     /// Translations for different languages, represented as a mapping from language codes to JSON values.
+    /// As `:` separates a namespace from its keys, a key such as `admin:title` belongs to the `admin` namespace.
+    /// Like the other catalogs, they are shared through an `Rc`: keep the same one between renders so that they
+    /// are compared by pointer and flattened only when replaced.
     #[prop_or_default]
-    pub translations: HashMap<String, serde_json::Value>,
+    pub translations: Rc<HashMap<String, serde_json::Value>>,
+    /// Translations of the other namespaces, represented as a mapping from namespaces to their own translations.
+    /// Their keys are accessed with `namespace:key`, while the keys of `translations` belong to the `common` namespace.
+    #[prop_or_default]
+    pub namespaces: Rc<HashMap<String, HashMap<String, serde_json::Value>>>,
+    /// Layers of translations on top of `translations` and `namespaces`, from the bottom to the top one,
+    /// each layer overriding the keys of the ones below.
+    #[prop_or_default]
+    pub layers: Rc<Vec<CatalogLayer>>,
+    #[cfg(feature = "fluent")]
+    /// Fluent resources for different languages, represented as a mapping from language codes to `.ftl` sources.
+    /// Their messages are looked for before the translations.
+    #[prop_or_default]
+    pub fluent_resources: Rc<HashMap<String, Vec<String>>>,
+    #[cfg(feature = "handlebars")]
+    /// The registry rendering the handlebars templates, with its own helpers, partials and settings, an empty one
+    /// if not specified. The `t`, `tt` and `lang` helpers are added to it, such as `{{t "key"}}`.
+    #[prop_or_default]
+    pub handlebars: HandlebarsRegistry,
     /// The current language code for translations, English if not specified.
     #[prop_or(String::from("en"))]
     pub current_language: String,
+    /// The `Accept-Language` header of the request, when rendering on the server.
+    /// The best matching supported language is used instead of `current_language`, if any.
+    #[prop_or_default]
+    pub accept_language: Option<String>,
+    /// What to write into the rendered page for hydration, nothing if not specified.
+    /// When hydrating, the language written by the server is used instead of `accept_language` and `current_language`.
+    #[prop_or_default]
+    pub hydration_state: HydrationState,
+    /// What the translation methods display at first, the translations if not specified.
+    #[prop_or_default]
+    pub show_keys: ShowKeys,
+    /// Where to write the `lang` and `dir` attributes of the current language, nowhere if not specified.
+    #[prop_or_default]
+    pub language_attributes: LanguageAttributes,
+    /// Called once per session for each key or data field missing in a language.
+    #[prop_or_default]
+    pub on_missing: Option<Callback<I18nEvent>>,
+    /// Called once per session for each template that failed to render in a language,
+    /// and with the error of `I18nProviderProps::validate` (with an empty key) if the configuration is inconsistent,
+    /// from an effect, so not while rendering on the server.
+    #[prop_or_default]
+    pub on_error: Option<Callback<I18nEvent>>,
+    /// A pseudo-locale added to the supported languages, whose translations are the ones of its source language
+    /// made to look foreign.
+    #[prop_or_default]
+    pub pseudo_localization: Option<PseudoLocalization>,
     /// The child components to be wrapped with the YewI18n context.
     pub children: Html,
 }
 
+impl I18nProviderProps {
+    /// Check that the configuration of the provider is consistent.
+    pub fn validate(&self) -> Result<(), I18nError> {
+        let error = self.language_error();
+        #[cfg(feature = "fluent")]
+        let error = error.or_else(|| fluent_error(&FluentCatalog::new(&self.fluent_resources)));
+        match error {
+            Some(message) => Err(I18nError::InvalidConfiguration(message)),
+            None => Ok(()),
+        }
+    }
+
+    /// Describe the inconsistency of the supported and current languages, if any.
+    fn language_error(&self) -> Option<String> {
+        if self.supported_languages.is_empty() {
+            return Some("No supported language has been provided".to_string());
+        }
+        let supported = self
+            .supported_languages
+            .iter()
+            .any(|language| language.tag == self.current_language)
+            || self
+                .pseudo_localization
+                .as_ref()
+                .is_some_and(|pseudo| pseudo.language == self.current_language);
+        (!supported).then(|| {
+            format!(
+                "The current language `{}` is not one of the supported languages",
+                self.current_language
+            )
+        })
+    }
+}
+
+#[cfg(feature = "fluent")]
+/// Describe the errors of the Fluent resources, if any.
+fn fluent_error(fluent: &FluentCatalog) -> Option<String> {
+    (!fluent.errors().is_empty())
+        .then(|| format!("Invalid Fluent resources: {}", fluent.errors().join(", ")))
+}
+
 /// Yew component for providing the YewI18n context to its children.
 #[function_component]
 pub fn I18nProvider(props: &I18nProviderProps) -> Html {
-    let translations = use_state(|| props.translations.clone());
-    let current_language = use_state(|| props.current_language.clone());
-    let supported_languages = use_state(|| props.supported_languages.clone());
+    let hydrated = use_state(|| {
+        (props.hydration_state != HydrationState::Off)
+            .then(HydrationData::read)
+            .flatten()
+            .filter(|data| {
+                props
+                    .supported_languages
+                    .iter()
+                    .chain(
+                        props
+                            .pseudo_localization
+                            .as_ref()
+                            .map(|pseudo| pseudo.language_info())
+                            .as_ref(),
+                    )
+                    .any(|language| language.tag == data.language)
+            })
+    });
+    // The catalogs kept between renders are the same `Rc`, compared by pointer.
+    let layers = use_memo(
+        (
+            props.translations.clone(),
+            props.namespaces.clone(),
+            props.layers.clone(),
+        ),
+        |(translations, namespaces, layers)| {
+            let mut layers = CatalogLayers::new(translations, namespaces, layers);
+            if let Some(HydrationData {
+                language,
+                translations: Some(catalog),
+            }) = &*hydrated
+            {
+                if !layers.has_language(language) {
+                    layers.0[0].1.insert(language.clone(), catalog.clone());
+                }
+            }
+            layers
+        },
+    );
+    let runtime = use_reducer(RuntimeTranslations::default);
+    let translations = use_memo(
+        (layers, runtime.version, props.pseudo_localization.clone()),
+        |(layers, _, pseudo)| {
+            let (translations, sources) = layers.compose(&runtime, pseudo.as_ref());
+            (Rc::new(translations), Rc::new(sources))
+        },
+    );
+    let current_language = use_state(|| {
+        hydrated
+            .as_ref()
+            .map(|data| data.language.clone())
+            .or_else(|| {
+                props.accept_language.as_deref().and_then(|header| {
+                    negotiate_language(&parse_accept_language(header), &props.supported_languages)
+                })
+            })
+            .unwrap_or_else(|| props.current_language.clone())
+    });
+    let supported_languages = use_memo(
+        (
+            props.supported_languages.clone(),
+            props.pseudo_localization.clone(),
+        ),
+        |(supported_languages, pseudo)| {
+            let mut supported_languages = supported_languages.clone();
+            if let Some(pseudo) = pseudo {
+                if !supported_languages
+                    .iter()
+                    .any(|language| language.tag == pseudo.language)
+                {
+                    supported_languages.push(pseudo.language_info());
+                }
+            }
+            supported_languages
+        },
+    );
+
+    let reported = use_mut_ref(Default::default);
+    #[cfg(feature = "fluent")]
+    let fluent = use_memo(
+        (
+            props.fluent_resources.clone(),
+            props.pseudo_localization.clone(),
+        ),
+        |(resources, pseudo)| {
+            FluentCatalog::new(resources).with_pseudo_localization(pseudo.clone())
+        },
+    );
+
+    let show_keys = use_state(|| props.show_keys);
+    #[cfg(feature = "handlebars")]
+    let handlebars = use_memo(props.handlebars.clone(), I18nRegistry::new);
 
     let set_language = {
         let current_language = current_language.clone();
         Callback::from(move |language: String| current_language.set(language.clone()))
     };
-    // |language_json| encode_json_to_hashmap(language_json)
+    let set_show_keys = {
+        let show_keys = show_keys.clone();
+        Callback::from(move |mode: ShowKeys| show_keys.set(mode))
+    };
+    let update_translations = {
+        let runtime = runtime.dispatcher();
+        Callback::from(move |update: TranslationsUpdate| runtime.dispatch(update))
+    };
     let i18n_handle = I18nHandler {
-        translations: (*translations)
-            .clone()
-            .iter()
-            .map(|(key, value)| (key.clone(), encode_json_to_hashmap(value)))
-            .collect(),
+        translations: translations.0.clone(),
+        sources: translations.1.clone(),
         set_language,
-        supported_languages: (*supported_languages).clone(),
+        show_keys: *show_keys,
+        set_show_keys,
+        update_translations,
+        supported_languages,
         current_language: (*current_language).clone(),
+        namespace: None,
+        scope: None,
+        reporter: I18nReporter {
+            on_missing: props.on_missing.clone(),
+            on_error: props.on_error.clone(),
+            reported,
+        },
+        #[cfg(feature = "fluent")]
+        fluent,
+        #[cfg(feature = "handlebars")]
+        handlebars: Some(handlebars),
+    };
+    {
+        // Like `validate`, reusing the parsed Fluent resources. Reported from an effect, so not on the server.
+        let error = props.language_error();
+        #[cfg(feature = "fluent")]
+        let error = error.or_else(|| fluent_error(&i18n_handle.fluent));
+        let i18n_handle = i18n_handle.clone();
+        use_effect_with(error, move |error| {
+            if let Some(message) = error {
+                i18n_handle.report_error("", &I18nError::InvalidConfiguration(message.clone()));
+            }
+        });
+    }
+    let lang = i18n_handle.current_language();
+    let dir = i18n_handle.direction().as_str();
+
+    {
+        let language_attributes = props.language_attributes;
+        use_effect_with((lang.clone(), dir), move |(lang, dir)| {
+            if language_attributes == LanguageAttributes::DocumentRoot {
+                if let Some(root) = web_sys::window()
+                    .and_then(|window| window.document())
+                    .and_then(|document| document.document_element())
+                {
+                    let _ = root.set_attribute("lang", lang);
+                    let _ = root.set_attribute("dir", dir);
+                }
+            }
+        });
+    }
+
+    let hydration_state = match props.hydration_state {
+        HydrationState::Off => Html::default(),
+        mode => {
+            let data = HydrationData {
+                language: lang.clone(),
+                translations: (mode == HydrationState::LanguageAndCatalog)
+                    .then(|| translations.0.get(&lang).cloned().unwrap_or_default()),
+            };
+            html!(<script type="application/json" id={HYDRATION_STATE_ID}>{ data.to_script() }</script>)
+        }
     };
-    html!(<ContextProvider<I18nHandler> context={i18n_handle.clone()}>{ props.children.clone() }</ContextProvider<I18nHandler>>)
+    let children = match props.language_attributes {
+        LanguageAttributes::Wrapper => html!(<div {lang} {dir}>{ props.children.clone() }</div>),
+        _ => props.children.clone(),
+    };
+    html! {
+        <ContextProvider<I18nHandler> context={i18n_handle}>
+            { children }
+            { hydration_state }
+        </ContextProvider<I18nHandler>>
+    }
 }
 
 #[cfg(test)]
 mod test {
     use std::collections::HashMap;
+    use std::rc::Rc;
 
     use serde::Serialize;
-    use yew::Callback;
+    use yew::{Callback, Reducible};
 
+    use super::super::test_utils::render;
+    use super::{
+        catalog_key, flatten_catalog, CatalogLayers, RuntimeTranslations, TranslationsUpdate,
+    };
     use crate::*;
 
     #[derive(Debug, Serialize)]
@@ -267,50 +1368,230 @@ mod test {
     }
 
     #[cfg(feature = "translation_templater")]
-    fn default_translation() -> HashMap<String, HashMap<String, String>> {
+    fn default_translation() -> Rc<Translations> {
         let mut translations = HashMap::new();
         translations.insert("en".to_string(), default_en_translation());
-        translations
+        Rc::new(translations)
     }
 
-    #[test]
     #[cfg(feature = "translation_templater")]
-    fn test_key_translation() {
-        let handler = I18nHandler {
+    fn default_handler() -> I18nHandler {
+        I18nHandler {
             current_language: "en".to_string(),
-            supported_languages: vec!["en"],
+            supported_languages: Rc::new(vec![LanguageInfo::from("en")]),
             set_language: Callback::noop(),
+            show_keys: ShowKeys::Off,
+            set_show_keys: Callback::noop(),
+            update_translations: Callback::noop(),
             translations: default_translation(),
-        };
+            sources: Default::default(),
+            namespace: None,
+            scope: None,
+            reporter: Default::default(),
+            #[cfg(feature = "fluent")]
+            fluent: Default::default(),
+            #[cfg(feature = "handlebars")]
+            handlebars: None,
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "translation_templater")]
+    fn test_key_translation() {
+        let handler = default_handler();
         assert_eq!(handler.t("key"), "Value.".to_string())
     }
 
     #[test]
     #[cfg(feature = "translation_templater")]
-    fn test_template_translation() {
-        let handler = I18nHandler {
-            current_language: "en".to_string(),
-            supported_languages: vec!["en"],
-            set_language: Callback::noop(),
+    fn test_handler_shares_translations() {
+        let handler = default_handler();
+        let rebuilt = I18nHandler {
             translations: default_translation(),
+            ..handler.clone()
+        };
+        assert_eq!(handler, handler.clone());
+        assert_ne!(handler, rebuilt);
+    }
+
+    #[test]
+    #[cfg(feature = "translation_templater")]
+    fn test_supported_languages() {
+        let handler = I18nHandler {
+            current_language: "ar".to_string(),
+            supported_languages: Rc::new(vec![
+                LanguageInfo::new("en", "English", "English"),
+                LanguageInfo::new("ar", "Arabic", "العربية"),
+            ]),
+            ..default_handler()
         };
+        assert_eq!(
+            handler.current_language_info().map(|info| info.direction),
+            Some(TextDirection::Rtl)
+        );
+        assert_eq!(handler.direction(), TextDirection::Rtl);
+        assert!(handler.set_language("en").is_ok());
+        assert!(matches!(
+            handler.set_language("fr"),
+            Err(I18nError::UnsupportedLanguage(language)) if language == "fr"
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "translation_templater")]
+    fn test_try_translation_errors() {
+        let handler = default_handler();
         let john = Person {
             name: "John".to_string(),
             childs: None,
             template: None,
         };
-        assert_eq!(handler.tt("hello", &john), "Hello John.".to_string())
+        assert_eq!(handler.try_tt("hello", &john).unwrap(), "Hello John.");
+        assert!(matches!(
+            handler.try_t("typo"),
+            Err(I18nError::MissingKey { key, .. }) if key == "typo"
+        ));
+        assert!(matches!(
+            handler.try_tt("useless_template", &john),
+            Err(I18nError::MissingData { key, .. }) if key == "childs.0.name"
+        ));
     }
 
     #[test]
     #[cfg(feature = "translation_templater")]
-    fn test_template_nested_translation() {
+    fn test_report_missing_once() {
+        use serde_json::json;
+        use std::cell::RefCell;
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut handler = default_handler();
+        handler.reporter.on_missing = Some({
+            let events = events.clone();
+            Callback::from(move |event| events.borrow_mut().push(event))
+        });
+        handler.tt("hard_hello", &json!({}));
+        handler.tt("hard_hello", &json!({}));
+        handler.t("typo");
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                I18nEvent {
+                    language: "en".to_string(),
+                    key: "name".to_string(),
+                    kind: I18nEventKind::MissingData,
+                    include_chain: vec!["hard_hello".to_string(), "hello".to_string()],
+                },
+                I18nEvent {
+                    language: "en".to_string(),
+                    key: "typo".to_string(),
+                    kind: I18nEventKind::MissingKey,
+                    include_chain: Vec::new(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "translation_templater")]
+    fn test_namespaced_translation() {
+        use serde_json::json;
+        let translations =
+            HashMap::from([("en".to_string(), json!({"title": "Home", "ok": "OK"}))]);
+        let namespaces = HashMap::from([(
+            "checkout".to_string(),
+            HashMap::from([(
+                "en".to_string(),
+                json!({"title": "Checkout", "confirm": "{{{title}}}: {{{ok}}}"}),
+            )]),
+        )]);
         let handler = I18nHandler {
-            current_language: "en".to_string(),
-            supported_languages: vec!["en"],
-            set_language: Callback::noop(),
-            translations: default_translation(),
+            translations: Rc::new(super::flatten_translations(&translations, &namespaces)),
+            ..default_handler()
         };
+        let checkout = handler.namespaced("checkout");
+        assert_eq!(handler.t("title"), "Home");
+        assert_eq!(handler.t("checkout:title"), "Checkout");
+        assert_eq!(checkout.t("title"), "Checkout");
+        assert_eq!(checkout.t("common:title"), "Home");
+        assert_eq!(checkout.t("ok"), "OK");
+        assert_eq!(checkout.tt("confirm", &json!({})), "Checkout: OK");
+    }
+
+    #[test]
+    #[cfg(feature = "translation_templater")]
+    fn test_namespaced_includes() {
+        use serde_json::json;
+        let translations = HashMap::from([(
+            "en".to_string(),
+            json!({"title": "Home", "ok": "OK", "greeting": "Welcome to {{{title}}}"}),
+        )]);
+        let namespaces = HashMap::from([(
+            "checkout".to_string(),
+            HashMap::from([(
+                "en".to_string(),
+                json!({
+                    "title": "Checkout",
+                    "summary": "{{{title}}}",
+                    "confirm": "{{{summary}}}: {{{ok}}}",
+                }),
+            )]),
+        )]);
+        let handler = I18nHandler {
+            translations: Rc::new(super::flatten_translations(&translations, &namespaces)),
+            ..default_handler()
+        };
+        assert_eq!(handler.tt("checkout:confirm", &json!({})), "Checkout: OK");
+        assert_eq!(
+            handler.try_tt("checkout:confirm", &json!({})).unwrap(),
+            "Checkout: OK"
+        );
+        let checkout = handler.namespaced("checkout");
+        assert_eq!(
+            checkout.tt("common:greeting", &json!({})),
+            "Welcome to Home"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "translation_templater")]
+    fn test_scoped_translation() {
+        use serde_json::json;
+        let translations = HashMap::from([(
+            "en".to_string(),
+            json!({
+                "ok": "OK",
+                "pages": {"settings": {"profile": {"title": "Profile", "hello": "Hello {{name}}"}}}
+            }),
+        )]);
+        let handler = I18nHandler {
+            translations: Rc::new(super::flatten_translations(&translations, &HashMap::new())),
+            ..default_handler()
+        };
+        let profile = handler.scoped("pages.settings").scoped("profile");
+        assert_eq!(profile.t("title"), "Profile");
+        assert_eq!(profile.tt("hello", &json!({"name": "John"})), "Hello John");
+        assert_eq!(profile.t("~ok"), "OK");
+        assert!(matches!(
+            profile.try_t("ok"),
+            Err(I18nError::MissingKey { key, .. }) if key == "pages.settings.profile.ok"
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "translation_templater")]
+    fn test_template_translation() {
+        let handler = default_handler();
+        let john = Person {
+            name: "John".to_string(),
+            childs: None,
+            template: None,
+        };
+        assert_eq!(handler.tt("hello", &john), "Hello John.".to_string())
+    }
+
+    #[test]
+    #[cfg(feature = "translation_templater")]
+    fn test_template_nested_translation() {
+        let handler = default_handler();
         let john = Person {
             name: "John".to_string(),
             childs: None,
@@ -325,12 +1606,7 @@ mod test {
     #[test]
     #[cfg(feature = "translation_templater")]
     fn test_template_value_pointer_translation() {
-        let handler = I18nHandler {
-            current_language: "en".to_string(),
-            supported_languages: vec!["en"],
-            set_language: Callback::noop(),
-            translations: default_translation(),
-        };
+        let handler = default_handler();
         let john = Person {
             name: "key".to_string(),
             childs: None,
@@ -345,12 +1621,7 @@ mod test {
     #[test]
     #[cfg(feature = "translation_templater")]
     fn test_template_value_template_pointer_translation() {
-        let handler = I18nHandler {
-            current_language: "en".to_string(),
-            supported_languages: vec!["en"],
-            set_language: Callback::noop(),
-            translations: default_translation(),
-        };
+        let handler = default_handler();
         let john = Person {
             name: "useless_template".to_string(),
             template: None,
@@ -369,12 +1640,7 @@ mod test {
     #[test]
     #[cfg(feature = "translation_templater")]
     fn test_template_value_template_injector_translation() {
-        let handler = I18nHandler {
-            current_language: "en".to_string(),
-            supported_languages: vec!["en"],
-            set_language: Callback::noop(),
-            translations: default_translation(),
-        };
+        let handler = default_handler();
         let childrens = vec![
             Person {
                 name: "Janne".to_string(),
@@ -413,16 +1679,452 @@ mod test {
     #[cfg(feature = "handlebars")]
     fn test_template_handlebars() {
         use serde_json::json;
-        let handler = I18nHandler {
-            current_language: "en".to_string(),
-            supported_languages: vec!["en"],
-            set_language: Callback::noop(),
-            translations: default_translation(),
-        };
+        let handler = default_handler();
         let result = match handler.thb("handlebars_1", &json!({"name": "foo"})) {
             Ok(result) => result,
             Err(_) => "".to_string(),
         };
         assert_eq!(result, "Hello foo".to_string())
     }
+
+    #[test]
+    #[cfg(feature = "fluent")]
+    fn test_fluent_translation() {
+        use serde_json::json;
+        let resources = HashMap::from([(
+            "en".to_string(),
+            vec![r#"-brand = Yew Translator
+welcome = Welcome to { -brand }!
+    .title = Home of { -brand }
+emails = { $count ->
+    [one] You have one email.
+   *[other] You have { $count } emails.
+}
+key = Fluent value.
+"#
+            .to_string()],
+        )]);
+        let mut handler = default_handler();
+        handler.fluent = Rc::new(FluentCatalog::new(&resources));
+        assert_eq!(handler.t("welcome"), "Welcome to Yew Translator!");
+        assert_eq!(handler.t("welcome.title"), "Home of Yew Translator");
+        assert_eq!(handler.t("key"), "Fluent value.");
+        assert_eq!(handler.t("hello"), "Hello {{name}}.");
+        assert_eq!(
+            handler.tt("emails", &json!({ "count": 1 })),
+            "You have one email."
+        );
+        assert_eq!(
+            handler.tt("emails", &json!({ "count": 3 })),
+            "You have 3 emails."
+        );
+        assert!(matches!(
+            handler.try_tt("emails", &json!({})),
+            Err(I18nError::Fluent(_))
+        ));
+        handler.fluent = Rc::new(
+            FluentCatalog::new(&resources)
+                .with_pseudo_localization(Some(PseudoLocalization::default().with_expansion(0.0))),
+        );
+        handler.current_language = "qps".to_string();
+        assert_eq!(handler.t("welcome.title"), "[Ĥöɱé öƒ Ýéŵ Ţŕåñšļåţöŕ]");
+        assert_eq!(
+            handler.tt("emails", &json!({ "count": 3 })),
+            "[Ýöû ĥåṽé 3 éɱåîļš.]"
+        );
+        let broken = HashMap::from([("en".to_string(), vec!["broken = {".to_string()])]);
+        assert!(!FluentCatalog::new(&broken).errors().is_empty());
+    }
+
+    #[test]
+    fn test_pseudo_translation() {
+        let mut handler = default_handler();
+        handler.translations = Rc::new(HashMap::from([(
+            "qps".to_string(),
+            HashMap::from([(
+                "hello".to_string(),
+                PseudoLocalization::default().localize("Hello {{name}}."),
+            )]),
+        )]));
+        handler.current_language = "qps".to_string();
+        assert_eq!(
+            handler.tt("hello", &serde_json::json!({ "name": "John" })),
+            "[Ĥéļļö John. ~~]"
+        );
+    }
+
+    #[test]
+    fn test_show_keys() {
+        let mut handler = default_handler();
+        handler.show_keys = ShowKeys::Keys;
+        assert_eq!(handler.t("key"), "key");
+        assert_eq!(handler.scoped("menu").t("home"), "menu.home");
+        handler.show_keys = ShowKeys::KeysWithLanguage;
+        assert_eq!(
+            handler.tt("hello", &serde_json::json!({ "name": "John" })),
+            "hello [en]"
+        );
+        assert_eq!(handler.tth("hello", &HashMap::new()), "hello [en]");
+        #[cfg(feature = "handlebars")]
+        assert_eq!(
+            handler.thb("handlebars_1", &serde_json::json!({})).unwrap(),
+            "handlebars_1 [en]"
+        );
+        assert_eq!(handler.try_t("key").unwrap(), "Value.");
+    }
+
+    #[test]
+    fn test_accept_language() {
+        let supported = [LanguageInfo::from("en"), LanguageInfo::from("fr")];
+        let preferred = parse_accept_language("de-CH, fr-CA;q=0.8, en;q=0.9, *;q=0.5, es;q=0");
+        assert_eq!(preferred, vec!["de-CH", "en", "fr-CA"]);
+        assert_eq!(
+            negotiate_language(&preferred, &supported),
+            Some("en".to_string())
+        );
+        assert_eq!(
+            negotiate_language(&parse_accept_language("fr-CA"), &supported),
+            Some("fr".to_string())
+        );
+        assert_eq!(negotiate_language(&preferred[..1], &supported), None);
+    }
+
+    #[tokio::test]
+    async fn test_server_side_rendering() {
+        use yew::prelude::*;
+
+        #[function_component]
+        fn Greeting() -> Html {
+            let i18n = use_translation();
+            html!(<p>{ i18n.t("hello") }</p>)
+        }
+
+        #[derive(Properties, PartialEq)]
+        struct AppProps {
+            accept_language: String,
+        }
+
+        #[function_component]
+        fn App(props: &AppProps) -> Html {
+            let translations = HashMap::from([
+                ("en".to_string(), serde_json::json!({ "hello": "Hello" })),
+                ("fr".to_string(), serde_json::json!({ "hello": "Bonjour" })),
+            ]);
+            html! {
+                <I18nProvider
+                    translations={Rc::new(translations)}
+                    accept_language={props.accept_language.clone()}
+                    language_attributes={LanguageAttributes::Wrapper}
+                >
+                    <Greeting />
+                </I18nProvider>
+            }
+        }
+
+        let rendered = render::<App>(AppProps {
+            accept_language: "fr-FR,fr;q=0.9,en;q=0.8".to_string(),
+        })
+        .await;
+        assert_eq!(rendered, r#"<div lang="fr" dir="ltr"><p>Bonjour</p></div>"#);
+    }
+
+    #[tokio::test]
+    async fn test_hydration_state() {
+        use yew::prelude::*;
+
+        #[function_component]
+        fn App() -> Html {
+            let translations = HashMap::from([
+                ("en".to_string(), serde_json::json!({ "hello": "Hello" })),
+                (
+                    "fr".to_string(),
+                    serde_json::json!({ "hello": "<b>Bonjour</b>" }),
+                ),
+            ]);
+            html! {
+                <I18nProvider
+                    translations={Rc::new(translations)}
+                    accept_language={"fr".to_string()}
+                    hydration_state={HydrationState::LanguageAndCatalog}
+                >
+                    <p>{ "Content" }</p>
+                </I18nProvider>
+            }
+        }
+
+        let rendered = render::<App>(()).await;
+        assert_eq!(
+            rendered,
+            r#"<p>Content</p><script type="application/json" id="yew-translator-state">{"language":"fr","translations":{"hello":"\u003cb\u003eBonjour\u003c/b\u003e"}}</script>"#
+        );
+    }
+
+    #[test]
+    fn test_report_invalid_configuration() {
+        use std::cell::RefCell;
+
+        let props = yew::props!(I18nProviderProps {
+            supported_languages: vec![LanguageInfo::from("en"), LanguageInfo::from("fr")],
+            current_language: "de".to_string(),
+            children: yew::Html::default(),
+        });
+        let message = "The current language `de` is not one of the supported languages";
+        assert!(matches!(
+            props.validate(),
+            Err(I18nError::InvalidConfiguration(error)) if error == message
+        ));
+        let pseudo = yew::props!(I18nProviderProps {
+            current_language: "qps".to_string(),
+            pseudo_localization: Some(PseudoLocalization::default()),
+            children: yew::Html::default(),
+        });
+        assert!(pseudo.validate().is_ok());
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut handler = default_handler();
+        handler.current_language = "de".to_string();
+        handler.reporter.on_error = Some({
+            let events = events.clone();
+            Callback::from(move |event| events.borrow_mut().push(event))
+        });
+        handler.report_error("", &props.validate().unwrap_err());
+        assert_eq!(
+            *events.borrow(),
+            vec![I18nEvent {
+                language: "de".to_string(),
+                key: String::new(),
+                kind: I18nEventKind::InvalidConfiguration(message.to_string()),
+                include_chain: Vec::new(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_handler_translations_update() {
+        use std::cell::RefCell;
+
+        let layers = CatalogLayers::new(
+            &HashMap::from([(
+                "en".to_string(),
+                serde_json::json!({ "menu": { "home": "Home", "about": "About" } }),
+            )]),
+            &HashMap::new(),
+            &[],
+        );
+        let runtime = Rc::new(RefCell::new(Rc::new(RuntimeTranslations::default())));
+        // Rebuild the handler from the updated translations, like the provider re-rendering its consumers.
+        let render = |update_translations: Callback<TranslationsUpdate>| {
+            let (translations, sources) = layers.compose(&runtime.borrow(), None);
+            I18nHandler {
+                translations: Rc::new(translations),
+                sources: Rc::new(sources),
+                update_translations,
+                ..default_handler()
+            }
+        };
+        let update_translations = {
+            let runtime = runtime.clone();
+            Callback::from(move |update: TranslationsUpdate| {
+                let reduced = runtime.borrow().clone().reduce(update);
+                *runtime.borrow_mut() = reduced;
+            })
+        };
+
+        let i18n = render(update_translations.clone());
+        assert_eq!(i18n.t("menu.about"), "About");
+        i18n.merge_translations(
+            "en",
+            &serde_json::json!({ "menu": { "about": "About us" }, "admin:menu": { "title": "Admin" } }),
+        );
+        i18n.remove_key("en", "menu.home");
+
+        let i18n = render(update_translations.clone());
+        assert_eq!(i18n.t("menu.about"), "About us");
+        assert_eq!(i18n.key_source("menu.about"), Some(KeySource::Runtime));
+        assert_eq!(i18n.t("admin:menu.title"), "Admin");
+        assert!(i18n.try_t("menu.home").is_err());
+        i18n.replace_translations("en", &serde_json::json!({ "title": "Title" }));
+
+        let i18n = render(update_translations);
+        assert_eq!(i18n.t("title"), "Title");
+        assert!(i18n.try_t("menu.about").is_err());
+    }
+
+    #[test]
+    fn test_runtime_translations_update() {
+        let layers = CatalogLayers::new(
+            &HashMap::from([(
+                "en".to_string(),
+                serde_json::json!({ "menu": { "home": "Home", "about": "About" } }),
+            )]),
+            &HashMap::new(),
+            &[CatalogLayer::new(
+                "tenant",
+                HashMap::from([(
+                    "en".to_string(),
+                    serde_json::json!({ "menu": { "about": "About Acme" } }),
+                )]),
+            )],
+        );
+        let pseudo = PseudoLocalization::default().with_expansion(0.0);
+        let runtime = Rc::new(RuntimeTranslations::default());
+
+        let runtime = runtime.reduce(TranslationsUpdate::Merge(
+            "en".to_string(),
+            flatten_catalog(&serde_json::json!({
+                "menu": { "about": "About us" },
+                "common:title": "Title",
+                "admin:menu": { "title": "Admin" },
+            })),
+        ));
+        let (translations, sources) = layers.compose(&runtime, Some(&pseudo));
+        let en = &translations["en"];
+        assert_eq!(en["menu.home"], "Home");
+        assert_eq!(en["menu.about"], "About us");
+        assert_eq!(en["title"], "Title");
+        assert_eq!(en["admin:menu.title"], "Admin");
+        assert_eq!(translations["qps"]["menu.about"], "[Åƀöûţ ûš]");
+        assert_eq!(sources["en"]["menu.about"], KeySource::Runtime);
+        assert_eq!(sources["qps"]["menu.about"], KeySource::Runtime);
+        assert!(!sources["en"].contains_key("menu.home"));
+
+        let remove = |runtime: Rc<RuntimeTranslations>, key: &str| {
+            runtime.reduce(TranslationsUpdate::RemoveKey(
+                "en".to_string(),
+                catalog_key(key).to_string(),
+            ))
+        };
+        let runtime = remove(runtime, "menu.about");
+        let (translations, sources) = layers.compose(&runtime, Some(&pseudo));
+        assert_eq!(translations["en"]["menu.about"], "About Acme");
+        assert_eq!(
+            sources["en"]["menu.about"],
+            KeySource::Layer("tenant".to_string())
+        );
+        let runtime = remove(runtime, "menu.about");
+        let runtime = remove(runtime, "common:menu.home");
+        let (translations, sources) = layers.compose(&runtime, Some(&pseudo));
+        assert_eq!(translations["en"]["menu.about"], "About");
+        assert!(!sources["en"].contains_key("menu.about"));
+        assert!(!translations["en"].contains_key("menu.home"));
+        assert!(!translations["qps"].contains_key("menu.home"));
+
+        let runtime = runtime.reduce(TranslationsUpdate::Replace(
+            "fr".to_string(),
+            flatten_catalog(&serde_json::json!({ "title": "Titre" })),
+        ));
+        let (translations, _) = layers.compose(&runtime, None);
+        assert_eq!(
+            translations["fr"],
+            HashMap::from([("title".to_string(), "Titre".to_string())])
+        );
+        assert_eq!(translations["en"].len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_catalog_layers() {
+        use yew::prelude::*;
+
+        #[function_component]
+        fn Keys() -> Html {
+            let i18n = use_translation();
+            let describe =
+                |key: &str| format!("{}={}:{:?};", key, i18n.t(key), i18n.key_source(key));
+            html!(<p>{ describe("brand") }{ describe("legal") }{ describe("hello") }{ describe("missing") }</p>)
+        }
+
+        #[function_component]
+        fn App() -> Html {
+            let translations = HashMap::from([(
+                "en".to_string(),
+                serde_json::json!({ "brand": "Acme", "legal": "Terms", "hello": "Hello" }),
+            )]);
+            let layers = vec![
+                CatalogLayer::new(
+                    "tenant",
+                    HashMap::from([(
+                        "en".to_string(),
+                        serde_json::json!({ "brand": "Globex", "legal": "Globex terms" }),
+                    )]),
+                ),
+                CatalogLayer::new(
+                    "experiment",
+                    HashMap::from([("en".to_string(), serde_json::json!({ "brand": "Globex+" }))]),
+                ),
+            ];
+            html! {
+                <I18nProvider translations={Rc::new(translations)} layers={Rc::new(layers)}>
+                    <Keys />
+                </I18nProvider>
+            }
+        }
+
+        let rendered = render::<App>(()).await;
+        assert_eq!(
+            rendered,
+            r#"<p>brand=Globex+:Some(Layer("experiment"));legal=Globex terms:Some(Layer("tenant"));hello=Hello:Some(Translations);missing=['EN'](T - 'missing'):None;</p>"#
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "handlebars")]
+    fn test_handlebars_registry_eq() {
+        use handlebars::Handlebars;
+        assert_eq!(HandlebarsRegistry::default(), HandlebarsRegistry::default());
+        let registry = HandlebarsRegistry::new(Handlebars::new());
+        assert_eq!(registry.clone(), registry);
+        assert_ne!(registry, HandlebarsRegistry::new(Handlebars::new()));
+        assert_ne!(registry, HandlebarsRegistry::default());
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "handlebars", feature = "translation_templater"))]
+    async fn test_handlebars_registry() {
+        use handlebars::{handlebars_helper, Handlebars};
+        use yew::prelude::*;
+
+        #[function_component]
+        fn Card() -> Html {
+            let i18n = use_translation();
+            let data = serde_json::json!({ "name": "john" });
+            html! {
+                <>
+                    <p>{ i18n.thb("card", &data).unwrap() }</p>
+                    <p>{ i18n.try_thb("missing_field", &data).is_err() }</p>
+                </>
+            }
+        }
+
+        #[function_component]
+        fn App() -> Html {
+            let translations = HashMap::from([(
+                "fr".to_string(),
+                serde_json::json!({
+                    "brand": "Acme",
+                    "greet": "Salut {{name}}",
+                    "card": "{{t \"brand\"}}: {{tt \"greet\" this}}, {{upper name}} ({{lang}})",
+                    "missing_field": "{{age}}",
+                }),
+            )]);
+            let handlebars = use_memo((), |_| {
+                handlebars_helper!(upper: |value: str| value.to_uppercase());
+                let mut registry = Handlebars::new();
+                registry.set_strict_mode(true);
+                registry.register_helper("upper", Box::new(upper));
+                HandlebarsRegistry::new(registry)
+            });
+            html! {
+                <I18nProvider
+                    translations={Rc::new(translations)}
+                    current_language="fr"
+                    handlebars={(*handlebars).clone()}
+                >
+                    <Card />
+                </I18nProvider>
+            }
+        }
+
+        let rendered = render::<App>(()).await;
+        assert_eq!(rendered, "<p>Acme: Salut john, JOHN (fr)</p><p>true</p>");
+    }
 }
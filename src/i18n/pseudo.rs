@@ -0,0 +1,168 @@
+use super::LanguageInfo;
+
+/// Accented look-alikes of the ASCII letters, `A` to `Z` then `a` to `z`.
+const ACCENTED: [char; 52] = [
+    'Å', 'Ɓ', 'Ç', 'Đ', 'É', 'Ƒ', 'Ĝ', 'Ĥ', 'Î', 'Ĵ', 'Ķ', 'Ļ', 'Ṁ', 'Ñ', 'Ö', 'Þ', 'Ǫ', 'Ŕ', 'Š',
+    'Ţ', 'Û', 'Ṽ', 'Ŵ', 'Ẋ', 'Ý', 'Ž', 'å', 'ƀ', 'ç', 'đ', 'é', 'ƒ', 'ĝ', 'ĥ', 'î', 'ĵ', 'ķ', 'ļ',
+    'ɱ', 'ñ', 'ö', 'þ', 'ǫ', 'ŕ', 'š', 'ţ', 'û', 'ṽ', 'ŵ', 'ẋ', 'ý', 'ž',
+];
+
+/// A pseudo-locale, whose translations are the ones of a source language made to look foreign,
+/// helping to spot hard-coded strings, truncations and concatenations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PseudoLocalization {
+    /// The code of the pseudo-locale, `qps` by default.
+    pub language: String,
+    /// The language whose translations are transformed, `en` by default.
+    pub source_language: String,
+    /// How much longer the text becomes, `0.3` (+30%) by default.
+    pub expansion: f32,
+    /// Whether the letters are replaced by accented look-alikes.
+    pub accents: bool,
+    /// Whether each translation is wrapped in `[` and `]`.
+    pub brackets: bool,
+}
+
+impl Default for PseudoLocalization {
+    fn default() -> Self {
+        Self {
+            language: "qps".to_string(),
+            source_language: "en".to_string(),
+            expansion: 0.3,
+            accents: true,
+            brackets: true,
+        }
+    }
+}
+
+impl PseudoLocalization {
+    /// Set the code of the pseudo-locale.
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = language.into();
+        self
+    }
+
+    /// Set the language whose translations are transformed.
+    pub fn with_source_language(mut self, source_language: impl Into<String>) -> Self {
+        self.source_language = source_language.into();
+        self
+    }
+
+    /// Set how much longer the text becomes (`0.3` for +30%).
+    pub fn with_expansion(mut self, expansion: f32) -> Self {
+        self.expansion = expansion;
+        self
+    }
+
+    /// Set whether the letters are replaced by accented look-alikes.
+    pub fn with_accents(mut self, accents: bool) -> Self {
+        self.accents = accents;
+        self
+    }
+
+    /// Set whether each translation is wrapped in `[` and `]`.
+    pub fn with_brackets(mut self, brackets: bool) -> Self {
+        self.brackets = brackets;
+        self
+    }
+
+    /// The metadata of the pseudo-locale.
+    pub fn language_info(&self) -> LanguageInfo {
+        LanguageInfo::new(self.language.clone(), "Pseudo-locale", "Ƥšéûđö-ļöçåļé")
+    }
+
+    /// Transform a template, leaving its `{{…}}` placeholders, escaped characters, HTML tags and entities untouched.
+    pub fn localize(&self, template: &str) -> String {
+        let mut result = String::new();
+        if self.brackets {
+            result.push('[');
+        }
+        let mut text_length = 0;
+        let mut rest = template;
+        while let Some(c) = rest.chars().next() {
+            if let Some(length) = verbatim_length(rest) {
+                result.push_str(&rest[..length]);
+                rest = &rest[length..];
+            } else {
+                text_length += 1;
+                result.push(self.accented(c));
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        let padding = (text_length as f32 * self.expansion).ceil() as usize;
+        if padding > 0 {
+            result.push(' ');
+            result.extend(std::iter::repeat_n('~', padding.saturating_sub(1)));
+        }
+        if self.brackets {
+            result.push(']');
+        }
+        result
+    }
+
+    fn accented(&self, c: char) -> char {
+        match c {
+            'A'..='Z' if self.accents => ACCENTED[c as usize - 'A' as usize],
+            'a'..='z' if self.accents => ACCENTED[c as usize - 'a' as usize + 26],
+            _ => c,
+        }
+    }
+}
+
+/// The length of the placeholder, escaped character, HTML tag or entity starting `text`, if it's one.
+fn verbatim_length(text: &str) -> Option<usize> {
+    let mut chars = text.chars();
+    match (chars.next()?, chars.next()?) {
+        ('\\', escaped) => Some(1 + escaped.len_utf8()),
+        ('{', '{') => {
+            let end = text.find("}}")?;
+            Some(text.len() - text[end..].trim_start_matches('}').len())
+        }
+        ('<', next) if next.is_ascii_alphabetic() || next == '/' => {
+            let end = text[1..].find(['<', '>'])? + 1;
+            text[end..].starts_with('>').then_some(end + 1)
+        }
+        ('&', next) if next.is_ascii_alphanumeric() || next == '#' => {
+            let end = text[1..].find(|c: char| !c.is_ascii_alphanumeric() && c != '#')? + 1;
+            text[end..].starts_with(';').then_some(end + 1)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PseudoLocalization;
+
+    #[test]
+    fn test_localize() {
+        let pseudo = PseudoLocalization::default();
+        assert_eq!(pseudo.localize("Value."), "[Ṽåļûé. ~]");
+        assert_eq!(
+            pseudo.localize("Hi {{name}}, <b>{{{*a}}}</b> &amp; \\{x\\}"),
+            "[Ĥî {{name}}, <b>{{{*a}}}</b> &amp; \\{ẋ\\} ~~]"
+        );
+        assert_eq!(
+            pseudo
+                .with_accents(false)
+                .with_brackets(false)
+                .with_expansion(1.0)
+                .localize("abc"),
+            "abc ~~"
+        );
+    }
+
+    #[test]
+    fn test_localize_unterminated() {
+        let pseudo = PseudoLocalization::default()
+            .with_accents(false)
+            .with_expansion(0.0);
+        assert_eq!(pseudo.localize("a<b"), "[a<b]");
+        assert_eq!(pseudo.localize("a<b and c<d>"), "[a<b and c<d>]");
+        assert_eq!(pseudo.localize("Tom & Jerry; &x"), "[Tom & Jerry; &x]");
+        assert_eq!(pseudo.localize("{{name"), "[{{name]");
+        let pseudo = PseudoLocalization::default().with_expansion(0.0);
+        assert_eq!(pseudo.localize("a<b"), "[å<ƀ]");
+        assert_eq!(pseudo.localize("a<b c<i>d</i>"), "[å<ƀ ç<i>đ</i>]");
+    }
+}
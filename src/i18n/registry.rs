@@ -0,0 +1,154 @@
+use super::I18nHandler;
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, RenderContext, RenderError, RenderErrorReason,
+    ScopedJson,
+};
+use serde::Serialize;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+thread_local! {
+    /// The handler rendering a template, used by the i18n helpers.
+    static RENDERING: RefCell<Option<I18nHandler>> = const { RefCell::new(None) };
+}
+
+/// A handlebars registry shared by the provider, configured once with its helpers, partials and settings.
+/// The default one is an empty registry, equal to every other default one.
+#[derive(Clone, Debug, Default)]
+pub struct HandlebarsRegistry(Option<Rc<Handlebars<'static>>>);
+
+impl HandlebarsRegistry {
+    /// Create a shared registry from a configured one.
+    pub fn new(registry: Handlebars<'static>) -> Self {
+        Self(Some(Rc::new(registry)))
+    }
+}
+
+impl From<Handlebars<'static>> for HandlebarsRegistry {
+    fn from(registry: Handlebars<'static>) -> Self {
+        Self::new(registry)
+    }
+}
+
+impl PartialEq for HandlebarsRegistry {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(registry), Some(other)) => Rc::ptr_eq(registry, other),
+            (registry, other) => registry.is_none() && other.is_none(),
+        }
+    }
+}
+
+/// The registry of a provider, with the i18n helpers and the templates already compiled, in every language.
+#[derive(Debug)]
+pub(crate) struct I18nRegistry {
+    registry: RefCell<Handlebars<'static>>,
+}
+
+impl I18nRegistry {
+    /// Copy a shared registry, adding the `t`, `tt` and `lang` helpers.
+    pub(crate) fn new(registry: &HandlebarsRegistry) -> Self {
+        let mut registry = registry.0.as_deref().cloned().unwrap_or_default();
+        registry.register_helper("t", Box::new(TranslateHelper));
+        #[cfg(feature = "translation_templater")]
+        registry.register_helper("tt", Box::new(TemplateHelper));
+        registry.register_helper("lang", Box::new(LanguageHelper));
+        Self {
+            registry: RefCell::new(registry),
+        }
+    }
+
+    /// Render a template with some data, compiling it on its first use.
+    pub(crate) fn render<T: Serialize>(
+        &self,
+        handler: &I18nHandler,
+        template: &str,
+        data: &T,
+    ) -> Result<String, RenderError> {
+        if !self.registry.borrow().has_template(template) {
+            self.registry
+                .borrow_mut()
+                .register_template_string(template, template)?;
+        }
+        let previous = RENDERING.with(|rendering| rendering.replace(Some(handler.clone())));
+        let result = self.registry.borrow().render(template, data);
+        RENDERING.with(|rendering| *rendering.borrow_mut() = previous);
+        result
+    }
+}
+
+/// Call `f` with the handler rendering the current template.
+fn with_handler<'rc>(
+    helper: &str,
+    f: impl FnOnce(&I18nHandler) -> String,
+) -> Result<ScopedJson<'rc>, RenderError> {
+    RENDERING.with(|rendering| match &*rendering.borrow() {
+        Some(handler) => Ok(ScopedJson::Derived(serde_json::Value::String(f(handler)))),
+        None => Err(RenderErrorReason::Other(format!(
+            "The `{}` helper is only available in the templates of an I18nHandler",
+            helper
+        ))
+        .into()),
+    })
+}
+
+/// The key given as the first parameter of a helper.
+fn key_param<'a>(helper: &'a Helper<'_>, name: &'static str) -> Result<&'a str, RenderError> {
+    helper
+        .param(0)
+        .and_then(|param| param.value().as_str())
+        .ok_or_else(|| RenderErrorReason::ParamNotFoundForIndex(name, 0).into())
+}
+
+/// `{{t "key"}}`: the translation of a key.
+struct TranslateHelper;
+
+impl HelperDef for TranslateHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        let key = key_param(h, "t")?;
+        with_handler("t", |handler| handler.t(key))
+    }
+}
+
+#[cfg(feature = "translation_templater")]
+/// `{{tt "key" data}}`: the translation of a key, injected some data.
+struct TemplateHelper;
+
+#[cfg(feature = "translation_templater")]
+impl HelperDef for TemplateHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        let key = key_param(h, "tt")?;
+        let data = h
+            .param(1)
+            .map(|param| param.value().clone())
+            .unwrap_or_default();
+        with_handler("tt", |handler| handler.tt(key, &data))
+    }
+}
+
+/// `{{lang}}`: the current language code.
+struct LanguageHelper;
+
+impl HelperDef for LanguageHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        _: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        with_handler("lang", |handler| handler.current_language())
+    }
+}
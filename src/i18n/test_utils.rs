@@ -0,0 +1,13 @@
+use yew::BaseComponent;
+
+/// Render a component on the server, without the hydration markers.
+pub(crate) async fn render<C>(props: C::Properties) -> String
+where
+    C: BaseComponent,
+    C::Properties: Send,
+{
+    yew::ServerRenderer::<C>::with_props(move || props)
+        .hydratable(false)
+        .render()
+        .await
+}
@@ -0,0 +1,193 @@
+use super::{use_translation, I18nError, I18nHandler};
+use crate::templater::{parse_to_hashmap, EscapeData};
+use yew::{function_component, html, AttrValue, Html, Properties};
+
+/// Configuration for the Trans component.
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct TransProps {
+    /// The key of the translation (`key` being reserved by yew for the lists).
+    pub i18n_key: AttrValue,
+    /// The data to inject in the template of the translation, if any.
+    #[prop_or_default]
+    pub data: Option<serde_json::Value>,
+    /// The language code to use instead of the current one.
+    #[prop_or_default]
+    pub language: Option<AttrValue>,
+    /// Whether the translation is rendered as HTML instead of text, the data injected with `{{field}}` (or as Fluent variables) being escaped.
+    #[prop_or_default]
+    pub html: bool,
+    /// Rendered instead of the translation when it can't be found or generated.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Escape the characters having a meaning in HTML.
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Translate the key of the props, escaping the data injected with `{{field}}` when rendering HTML.
+fn translate(handler: &I18nHandler, props: &TransProps) -> Result<String, I18nError> {
+    let data = match &props.data {
+        Some(data) => parse_to_hashmap(data)?,
+        None => Default::default(),
+    };
+    let escape_data: Option<EscapeData> = match props.html {
+        true => Some(Box::new(escape_html)),
+        false => None,
+    };
+    handler.try_tth_escaped(&props.i18n_key, &data, escape_data)
+}
+
+/// Yew component displaying a translation, as text or as HTML with the `html` prop.
+#[function_component]
+pub fn Trans(props: &TransProps) -> Html {
+    let handler = use_translation();
+    let handler = match &props.language {
+        Some(language) => handler.in_language(language.as_str()),
+        None => handler,
+    };
+    if let Some(key) = handler.shown_key(&props.i18n_key) {
+        return html!({ key });
+    }
+    match translate(&handler, props) {
+        Ok(translation) if props.html => Html::from_html_unchecked(AttrValue::from(translation)),
+        Ok(translation) => html!({ translation }),
+        Err(err) if props.children != Html::default() => {
+            handler.report_error(&props.i18n_key, &err);
+            props.children.clone()
+        }
+        Err(_) => {
+            let translation = match &props.data {
+                Some(data) => handler.tt(&props.i18n_key, data),
+                None => handler.t(&props.i18n_key),
+            };
+            html!({ translation })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    use serde_json::json;
+    use yew::prelude::*;
+
+    use super::super::test_utils::render;
+    use super::Trans;
+    use crate::I18nProvider;
+
+    /// Wrap some content with a provider holding the translations of the tests.
+    fn with_provider(children: Html) -> Html {
+        let translations = HashMap::from([
+            (
+                "en".to_string(),
+                json!({
+                    "compare": "a < b",
+                    "bold": "<b>Hello</b>",
+                    "greeting": "<b>{{name}}</b>",
+                    "welcome": "{{{bold}}} {{name}}",
+                    "pet": "{{*pet}}",
+                    "pets": { "cat&dog": "<i>Cats & dogs</i>" },
+                }),
+            ),
+            ("fr".to_string(), json!({ "bold": "<b>Bonjour</b>" })),
+        ]);
+        html!(<I18nProvider translations={Rc::new(translations)}>{ children }</I18nProvider>)
+    }
+
+    #[tokio::test]
+    async fn test_trans_text() {
+        #[function_component]
+        fn App() -> Html {
+            with_provider(html! {
+                <>
+                    <p><Trans i18n_key="compare" /></p>
+                    <p><Trans i18n_key="bold" /></p>
+                    <p><Trans i18n_key="greeting" data={json!({ "name": "<i>" })} /></p>
+                </>
+            })
+        }
+
+        assert_eq!(
+            render::<App>(()).await,
+            "<p>a &lt; b</p><p>&lt;b&gt;Hello&lt;/b&gt;</p><p>&lt;b&gt;&lt;i&gt;&lt;/b&gt;</p>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_trans_html() {
+        #[function_component]
+        fn App() -> Html {
+            with_provider(html! {
+                <>
+                    <p><Trans i18n_key="greeting" data={json!({ "name": "<i>&" })} html=true /></p>
+                    <p><Trans i18n_key="welcome" data={json!({ "name": "Tom & Jerry" })} html=true /></p>
+                    <p><Trans i18n_key="pet" data={json!({ "pet": "pets.cat&dog" })} html=true /></p>
+                    <p><Trans i18n_key="bold" language="fr" html=true /></p>
+                </>
+            })
+        }
+
+        assert_eq!(
+            render::<App>(()).await,
+            "<p><b>&lt;i&gt;&amp;</b></p><p><b>Hello</b> Tom &amp; Jerry</p><p><i>Cats & dogs</i></p><p><b>Bonjour</b></p>"
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "fluent")]
+    async fn test_trans_html_fluent() {
+        #[function_component]
+        fn App() -> Html {
+            let fluent_resources = HashMap::from([(
+                "en".to_string(),
+                vec!["hello = <b>{ $name }</b>\nemails = <i>{ $count ->\n    [one] One email\n   *[other] { $count } emails\n}</i>\n".to_string()],
+            )]);
+            html! {
+                <I18nProvider fluent_resources={Rc::new(fluent_resources)}>
+                    <p><Trans i18n_key="hello" data={json!({ "name": "<img src=x onerror=alert(1)>" })} html=true /></p>
+                    <p><Trans i18n_key="emails" data={json!({ "count": 1 })} html=true /></p>
+                    <p><Trans i18n_key="hello" data={json!({ "name": "<i>" })} /></p>
+                </I18nProvider>
+            }
+        }
+
+        assert_eq!(
+            render::<App>(()).await,
+            "<p><b>&lt;img src=x onerror=alert(1)&gt;</b></p><p><i>One email</i></p><p>&lt;b&gt;&lt;i&gt;&lt;/b&gt;</p>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_trans_fallback() {
+        #[function_component]
+        fn App() -> Html {
+            with_provider(html! {
+                <>
+                    <p><Trans i18n_key="missing">{ "Fallback" }</Trans></p>
+                    <p><Trans i18n_key="greeting">{ "No name" }</Trans></p>
+                    <p><Trans i18n_key="missing" /></p>
+                </>
+            })
+        }
+
+        assert_eq!(
+            render::<App>(()).await,
+            "<p>Fallback</p><p>No name</p><p>['EN'](T - 'missing')</p>"
+        );
+    }
+}
@@ -6,3 +6,9 @@ use yew::{hook, use_context};
 pub fn use_translation() -> I18nHandler {
     use_context::<I18nHandler>().expect("No I18n handle context provided")
 }
+
+/// Hook to use the I18nHandler, looking the unprefixed keys up in `namespace` first.
+#[hook]
+pub fn use_translation_ns(namespace: &str) -> I18nHandler {
+    use_translation().namespaced(namespace)
+}
@@ -1,8 +1,30 @@
+#[cfg(feature = "codegen")]
+mod codegen;
+#[cfg(any(
+    feature = "gettext",
+    feature = "yaml",
+    feature = "toml",
+    feature = "xliff"
+))]
+mod formats;
 #[cfg(feature = "yew-i18n")]
 mod i18n;
 #[cfg(feature = "translation_templater")]
+#[cfg_attr(
+    not(any(feature = "yew-i18n", feature = "export_translation_templater")),
+    allow(dead_code, unused_imports)
+)]
 mod templater;
 
+#[cfg(feature = "codegen")]
+pub use codegen::*;
+#[cfg(any(
+    feature = "gettext",
+    feature = "yaml",
+    feature = "toml",
+    feature = "xliff"
+))]
+pub use formats::*;
 #[cfg(feature = "yew-i18n")]
 pub use i18n::*;
 #[cfg(feature = "export_translation_templater")]
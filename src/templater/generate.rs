@@ -1,13 +1,28 @@
 use std::collections::HashMap;
 
-use super::{StringTemplaterError, StringTemplaterOptions};
+use super::placeholders::read_placeholder;
+use super::{
+    MissingField, MissingFieldKind, Placeholder, PlaceholderKind, StringTemplaterError,
+    StringTemplaterOptions, TranslationLookup,
+};
 
 /// Generate the template with options using translation and data.
-pub fn generate(
+pub fn generate<L: TranslationLookup + ?Sized>(
     template_str: &str,
-    translation: &HashMap<String, String>,
+    translation: &L,
     data: &HashMap<String, String>,
     option: &StringTemplaterOptions,
+) -> Result<String, StringTemplaterError> {
+    generate_included(template_str, translation, data, option, &mut Vec::new())
+}
+
+/// Generate the template, keeping track of the translation keys included to reach it.
+fn generate_included<L: TranslationLookup + ?Sized>(
+    template_str: &str,
+    translation: &L,
+    data: &HashMap<String, String>,
+    option: &StringTemplaterOptions,
+    include_chain: &mut Vec<String>,
 ) -> Result<String, StringTemplaterError> {
     let mut result = String::new();
     let mut chars = template_str.chars().peekable();
@@ -17,234 +32,99 @@ pub fn generate(
                 if let Some('{') = chars.peek() {
                     chars.next();
 
-                    let mut apply_template = false;
-                    let mut pointer = false;
-                    let mut inject = false;
-                    let mut key = String::new();
-
-                    // Templating
-                    if let Some('{') = chars.peek() {
-                        apply_template = true;
-                        chars.next();
-                    }
-
-                    // Pointer
-                    if let Some('*') = chars.peek() {
-                        pointer = true;
-                        chars.next();
-                        if let Some('*') = chars.peek() {
-                            inject = apply_template; // inject can only be true in a template
-                            pointer = !apply_template; // If inject, then bye pointer
-                            chars.next();
-                        }
-                    }
-
-                    // Key looking
-                    while let Some(&next) = chars.peek() {
-                        if next == '}' {
-                            break;
-                        } else if next == '\\' {
-                            chars.next();
-                            match chars.peek() {
-                                Some('*') => {
-                                    chars.next();
-                                    key.push('*');
-                                }
-                                Some('\\') => {
-                                    chars.next();
-                                    key.push('\\');
-                                }
-                                Some('{') => {
-                                    chars.next();
-                                    key.push('{');
-                                }
-                                Some('}') => {
-                                    chars.next();
-                                    key.push('}');
-                                }
-                                _ => key.push('\\'),
-                            }
-                        } else {
-                            key.push(chars.next().unwrap());
-                        }
-                    }
-
-                    // Error handling
-                    if let Some('}') = chars.peek() {
-                        chars.next();
-                    } else if apply_template {
-                        return Err(StringTemplaterError::MissingCurvyBracket(format!(
-                            "Missing three curvy bracket `}}` around `{}`.",
-                            key
-                        )));
-                    } else {
-                        return Err(StringTemplaterError::MissingCurvyBracket(format!(
-                            "Missing two curvy bracket `}}` around `{}`.",
-                            key
-                        )));
-                    }
-                    if let Some('}') = chars.peek() {
-                        chars.next();
-                    } else if apply_template {
-                        return Err(StringTemplaterError::MissingCurvyBracket(format!(
-                            "Missing two curvy bracket `}}` around `{}`.",
-                            key
-                        )));
-                    } else {
-                        return Err(StringTemplaterError::MissingCurvyBracket(format!(
-                            "Missing one curvy bracket `}}` around `{}`.",
-                            key
-                        )));
-                    }
-                    if apply_template {
-                        if let Some('}') = chars.peek() {
-                            chars.next();
-                        } else {
-                            return Err(StringTemplaterError::MissingCurvyBracket(format!(
-                                "Missing one curvy bracket `}}` around `{}`.",
-                                key
-                            )));
-                        }
-                    }
+                    let Placeholder { kind, key } = read_placeholder(&mut chars)?;
 
                     // Data handling
-                    if apply_template {
-                        if pointer {
+                    match kind {
+                        PlaceholderKind::PointerInclude => {
                             if let Some(value) = data.get(&key) {
-                                if let Some(value) = translation.get(value) {
-                                    match generate(value, translation, data, option) {
-                                        Ok(value) => {
-                                            result.push_str(&value);
-                                        }
-                                        Err(err) => return Err(err),
-                                    };
-                                } else if option.safe_parse {
-                                    if option.display_missing_translations {
-                                        let patched_value =
-                                            option.override_missing_translations.as_ref();
-                                        let patched_value =
-                                            patched_value.map(|f| f(value)).unwrap_or(format!(
-                                                "[MISSING_TRANSLATION_KEY: `{}`]",
-                                                value
-                                            ));
-                                        result.push_str(&patched_value);
-                                    }
+                                if let Some(template) = translation.lookup(value) {
+                                    include_chain.push(value.clone());
+                                    let value = generate_included(
+                                        template,
+                                        translation,
+                                        data,
+                                        option,
+                                        include_chain,
+                                    )?;
+                                    include_chain.pop();
+                                    result.push_str(&value);
                                 } else {
-                                    return Err(StringTemplaterError::UnknownField(format!(
-                                        "The field `{}` does not exist in translations.",
-                                        key
-                                    )));
-                                }
-                            } else if option.safe_parse {
-                                if option.display_missing_keys {
-                                    let patched_value = option.override_missing_keys.as_ref();
-                                    let patched_value = patched_value
-                                        .map(|f| f(&key))
-                                        .unwrap_or(format!("[MISSING_DATA_KEY: `{}`]", key));
-                                    result.push_str(&patched_value);
+                                    missing_translation(
+                                        value,
+                                        &key,
+                                        option,
+                                        include_chain,
+                                        &mut result,
+                                    )?;
                                 }
                             } else {
-                                return Err(StringTemplaterError::UnknownField(format!(
-                                    "The field `{}` does not exist in data.",
-                                    key
-                                )));
+                                missing_data(&key, option, include_chain, &mut result)?;
                             }
-                        } else if inject {
+                        }
+                        PlaceholderKind::Inject => {
                             if let Some(value) = data.get(&key) {
-                                match generate(value, translation, data, option) {
-                                    Ok(value) => {
-                                        result.push_str(&value);
-                                    }
-                                    Err(err) => return Err(err),
-                                };
-                            } else if option.safe_parse {
-                                if option.display_missing_keys {
-                                    let patched_value = option.override_missing_keys.as_ref();
-                                    let patched_value = patched_value
-                                        .map(|f| f(&key))
-                                        .unwrap_or(format!("[MISSING_DATA_KEY: `{}`]", key));
-                                    result.push_str(&patched_value);
-                                }
+                                let value = generate_included(
+                                    value,
+                                    translation,
+                                    data,
+                                    option,
+                                    include_chain,
+                                )?;
+                                result.push_str(&value);
                             } else {
-                                return Err(StringTemplaterError::UnknownField(format!(
-                                    "The field `{}` does not exist in data.",
-                                    key
-                                )));
+                                missing_data(&key, option, include_chain, &mut result)?;
                             }
-                        } else if let Some(value) = translation.get(&key) {
-                            match generate(value, translation, data, option) {
-                                Ok(value) => {
-                                    result.push_str(&value);
-                                }
-                                Err(err) => return Err(err),
-                            };
-                        } else if option.safe_parse {
-                            if option.display_missing_translations {
-                                let patched_value = option.override_missing_translations.as_ref();
-                                let patched_value = patched_value
-                                    .map(|f| f(&key))
-                                    .unwrap_or(format!("[MISSING_TRANSLATION_KEY: `{}`]", key));
-                                result.push_str(&patched_value);
+                        }
+                        PlaceholderKind::Include => {
+                            if let Some(template) = translation.lookup(&key) {
+                                include_chain.push(key.clone());
+                                let value = generate_included(
+                                    template,
+                                    translation,
+                                    data,
+                                    option,
+                                    include_chain,
+                                )?;
+                                include_chain.pop();
+                                result.push_str(&value);
+                            } else {
+                                missing_translation(
+                                    &key,
+                                    &key,
+                                    option,
+                                    include_chain,
+                                    &mut result,
+                                )?;
                             }
-                        } else {
-                            return Err(StringTemplaterError::UnknownField(format!(
-                                "The field `{}` does not exist in translations.",
-                                key
-                            )));
                         }
-                    } else {
-                        // Not template
-                        if pointer {
+                        PlaceholderKind::Pointer => {
                             // Use the value of a data as a translation key
                             if let Some(value) = data.get(&key) {
-                                if let Some(value) = translation.get(value) {
+                                if let Some(value) = translation.lookup(value) {
                                     result.push_str(value);
-                                } else if option.safe_parse {
-                                    if option.display_missing_translations {
-                                        let patched_value =
-                                            option.override_missing_translations.as_ref();
-                                        let patched_value =
-                                            patched_value.map(|f| f(value)).unwrap_or(format!(
-                                                "[MISSING_TRANSLATION_KEY: `{}`]",
-                                                value
-                                            ));
-                                        result.push_str(&patched_value);
-                                    }
                                 } else {
-                                    return Err(StringTemplaterError::UnknownField(format!(
-                                        "The field `{}` does not exist in translations.",
-                                        key
-                                    )));
-                                }
-                            } else if option.safe_parse {
-                                if option.display_missing_keys {
-                                    let patched_value = option.override_missing_keys.as_ref();
-                                    let patched_value = patched_value
-                                        .map(|f| f(&key))
-                                        .unwrap_or(format!("[MISSING_DATA_KEY: `{}`]", key));
-                                    result.push_str(&patched_value);
+                                    missing_translation(
+                                        value,
+                                        &key,
+                                        option,
+                                        include_chain,
+                                        &mut result,
+                                    )?;
                                 }
                             } else {
-                                return Err(StringTemplaterError::UnknownField(format!(
-                                    "The field `{}` does not exist in data.",
-                                    key
-                                )));
+                                missing_data(&key, option, include_chain, &mut result)?;
                             }
-                        } else if let Some(value) = data.get(&key) {
-                            result.push_str(value);
-                        } else if option.safe_parse {
-                            if option.display_missing_keys {
-                                let patched_value = option.override_missing_keys.as_ref();
-                                let patched_value = patched_value
-                                    .map(|f| f(&key))
-                                    .unwrap_or(format!("[MISSING_DATA_KEY: `{}`]", key));
-                                result.push_str(&patched_value);
+                        }
+                        PlaceholderKind::Data => {
+                            if let Some(value) = data.get(&key) {
+                                match option.escape_data.as_ref() {
+                                    Some(escape) => result.push_str(&escape(value)),
+                                    None => result.push_str(value),
+                                }
+                            } else {
+                                missing_data(&key, option, include_chain, &mut result)?;
                             }
-                        } else {
-                            return Err(StringTemplaterError::UnknownField(format!(
-                                "The field `{}` does not exist in data.",
-                                key
-                            )));
                         }
                     }
                 } else {
@@ -274,3 +154,68 @@ pub fn generate(
     }
     Ok(result)
 }
+
+/// Report a field missing from the data, then either display it or fail depending on the options.
+fn missing_data(
+    key: &String,
+    option: &StringTemplaterOptions,
+    include_chain: &[String],
+    result: &mut String,
+) -> Result<(), StringTemplaterError> {
+    report_missing(option, MissingFieldKind::Data, key, include_chain);
+    if !option.safe_parse {
+        return Err(StringTemplaterError::UnknownField(format!(
+            "The field `{}` does not exist in data.",
+            key
+        )));
+    }
+    if option.display_missing_keys {
+        let patched_value = option.override_missing_keys.as_ref();
+        let patched_value = patched_value
+            .map(|f| f(key))
+            .unwrap_or(format!("[MISSING_DATA_KEY: `{}`]", key));
+        result.push_str(&patched_value);
+    }
+    Ok(())
+}
+
+/// Report a key missing from the translations, then either display it or fail depending on the options.
+/// The `field` is the name written in the template that lead to the missing key.
+fn missing_translation(
+    key: &String,
+    field: &str,
+    option: &StringTemplaterOptions,
+    include_chain: &[String],
+    result: &mut String,
+) -> Result<(), StringTemplaterError> {
+    report_missing(option, MissingFieldKind::Translation, key, include_chain);
+    if !option.safe_parse {
+        return Err(StringTemplaterError::UnknownField(format!(
+            "The field `{}` does not exist in translations.",
+            field
+        )));
+    }
+    if option.display_missing_translations {
+        let patched_value = option.override_missing_translations.as_ref();
+        let patched_value = patched_value
+            .map(|f| f(key))
+            .unwrap_or(format!("[MISSING_TRANSLATION_KEY: `{}`]", key));
+        result.push_str(&patched_value);
+    }
+    Ok(())
+}
+
+fn report_missing(
+    option: &StringTemplaterOptions,
+    kind: MissingFieldKind,
+    key: &str,
+    include_chain: &[String],
+) {
+    if let Some(report) = option.report_missing.as_ref() {
+        report(&MissingField {
+            kind,
+            key: key.to_string(),
+            include_chain: include_chain.to_vec(),
+        });
+    }
+}
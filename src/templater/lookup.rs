@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+
+/// A source of translations in which the templates look their keys up.
+pub trait TranslationLookup {
+    /// Find the template of a translation key.
+    fn lookup(&self, key: &str) -> Option<&String>;
+}
+
+impl TranslationLookup for HashMap<String, String> {
+    fn lookup(&self, key: &str) -> Option<&String> {
+        self.get(key)
+    }
+}
@@ -1,9 +1,17 @@
 mod errors;
 mod generate;
+mod lookup;
 mod options;
 mod parse_to_hashmap;
+mod placeholders;
 
 pub use errors::StringTemplaterError;
 pub use generate::generate;
-pub use options::StringTemplaterOptions;
+pub use lookup::TranslationLookup;
+pub use options::{EscapeData, MissingField, MissingFieldKind, StringTemplaterOptions};
+#[cfg(feature = "export_translation_templater")]
+pub use options::{OverrideMessage, ReportMissing};
 pub use parse_to_hashmap::{encode_json_to_hashmap, parse_to_hashmap};
+#[cfg(feature = "export_translation_templater")]
+pub use placeholders::{placeholders, required_fields};
+pub use placeholders::{Placeholder, PlaceholderKind};
@@ -1,4 +1,26 @@
 pub type OverrideMessage = Box<dyn Fn(&String) -> String>;
+pub type ReportMissing = Box<dyn Fn(&MissingField)>;
+pub type EscapeData = Box<dyn Fn(&str) -> String>;
+
+/// Where a missing field was looked for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MissingFieldKind {
+    /// The field is missing from the data.
+    Data,
+    /// The key is missing from the translations.
+    Translation,
+}
+
+/// A field that couldn't be found while generating a template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingField {
+    /// Where the field was looked for.
+    pub kind: MissingFieldKind,
+    /// The name of the missing field.
+    pub key: String,
+    /// The translation keys included (`{{{key}}}`) to reach the template containing the missing field, outermost first.
+    pub include_chain: Vec<String>,
+}
 
 /// Options for the string templater.
 #[derive(Default)]
@@ -13,4 +35,9 @@ pub struct StringTemplaterOptions {
     pub display_missing_translations: bool,
     /// Override the message for the missing translations field.
     pub override_missing_translations: Option<OverrideMessage>,
+    /// Called for every missing data field or translation key, whether it's displayed or not.
+    pub report_missing: Option<ReportMissing>,
+    /// Transform the data values injected with `{{field}}`, such as escaping them for HTML.
+    /// The data used as keys or templates is left untouched.
+    pub escape_data: Option<EscapeData>,
 }
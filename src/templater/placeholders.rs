@@ -0,0 +1,219 @@
+#[cfg(feature = "export_translation_templater")]
+use std::collections::BTreeSet;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use super::StringTemplaterError;
+#[cfg(feature = "export_translation_templater")]
+use super::TranslationLookup;
+
+/// What a placeholder of a template refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PlaceholderKind {
+    /// `{{field}}`: inject the value of a data field.
+    Data,
+    /// `{{*field}}`: inject the translation whose key is the value of a data field.
+    Pointer,
+    /// `{{{key}}}`: inject the template of a translation key.
+    Include,
+    /// `{{{*field}}}`: inject the template whose translation key is the value of a data field.
+    PointerInclude,
+    /// `{{{**field}}}`: inject the value of a data field as a template.
+    Inject,
+}
+
+#[cfg(feature = "export_translation_templater")]
+impl PlaceholderKind {
+    /// Whether the key of the placeholder is the name of a data field.
+    pub fn is_data(&self) -> bool {
+        !matches!(self, PlaceholderKind::Include)
+    }
+}
+
+/// A placeholder found in a template.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Placeholder {
+    /// What the placeholder refers to.
+    pub kind: PlaceholderKind,
+    /// The data field or translation key of the placeholder.
+    pub key: String,
+}
+
+impl fmt::Display for Placeholder {
+    /// Write the placeholder the way it is written in a template, such as `{{*field}}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            PlaceholderKind::Data => write!(f, "{{{{{}}}}}", self.key),
+            PlaceholderKind::Pointer => write!(f, "{{{{*{}}}}}", self.key),
+            PlaceholderKind::Include => write!(f, "{{{{{{{}}}}}}}", self.key),
+            PlaceholderKind::PointerInclude => write!(f, "{{{{{{*{}}}}}}}", self.key),
+            PlaceholderKind::Inject => write!(f, "{{{{{{**{}}}}}}}", self.key),
+        }
+    }
+}
+
+#[cfg(feature = "export_translation_templater")]
+/// List the placeholders of a template, in order of appearance.
+pub fn placeholders(template_str: &str) -> Result<Vec<Placeholder>, StringTemplaterError> {
+    let mut placeholders = Vec::new();
+    let mut chars = template_str.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                if let Some('{') = chars.peek() {
+                    chars.next();
+                    placeholders.push(read_placeholder(&mut chars)?);
+                }
+            }
+            '\\' => {
+                if let Some('{' | '}' | '\\') = chars.peek() {
+                    chars.next();
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(placeholders)
+}
+
+#[cfg(feature = "export_translation_templater")]
+/// Collect the data fields required by the template of `key`, following its `{{{…}}}` includes.
+pub fn required_fields<L: TranslationLookup + ?Sized>(
+    translation: &L,
+    key: &str,
+) -> Result<BTreeSet<String>, StringTemplaterError> {
+    let mut fields = BTreeSet::new();
+    collect_fields(translation, key, &mut fields, &mut BTreeSet::new())?;
+    Ok(fields)
+}
+
+#[cfg(feature = "export_translation_templater")]
+fn collect_fields<L: TranslationLookup + ?Sized>(
+    translation: &L,
+    key: &str,
+    fields: &mut BTreeSet<String>,
+    visited: &mut BTreeSet<String>,
+) -> Result<(), StringTemplaterError> {
+    if !visited.insert(key.to_string()) {
+        return Ok(());
+    }
+    let Some(template) = translation.lookup(key) else {
+        return Err(StringTemplaterError::UnknownField(format!(
+            "The field `{}` does not exist in translations.",
+            key
+        )));
+    };
+    for placeholder in placeholders(template)? {
+        if placeholder.kind.is_data() {
+            fields.insert(placeholder.key);
+        } else {
+            collect_fields(translation, &placeholder.key, fields, visited)?;
+        }
+    }
+    Ok(())
+}
+
+/// Read a placeholder right after its opening `{{`, up to its closing curvy brackets.
+pub(crate) fn read_placeholder(
+    chars: &mut Peekable<Chars>,
+) -> Result<Placeholder, StringTemplaterError> {
+    let mut apply_template = false;
+    let mut pointer = false;
+    let mut inject = false;
+    let mut key = String::new();
+
+    // Templating
+    if let Some('{') = chars.peek() {
+        apply_template = true;
+        chars.next();
+    }
+
+    // Pointer
+    if let Some('*') = chars.peek() {
+        pointer = true;
+        chars.next();
+        if let Some('*') = chars.peek() {
+            inject = apply_template; // inject can only be true in a template
+            pointer = !apply_template; // If inject, then bye pointer
+            chars.next();
+        }
+    }
+
+    // Key looking
+    while let Some(&next) = chars.peek() {
+        if next == '}' {
+            break;
+        } else if next == '\\' {
+            chars.next();
+            match chars.peek() {
+                Some('*') => {
+                    chars.next();
+                    key.push('*');
+                }
+                Some('\\') => {
+                    chars.next();
+                    key.push('\\');
+                }
+                Some('{') => {
+                    chars.next();
+                    key.push('{');
+                }
+                Some('}') => {
+                    chars.next();
+                    key.push('}');
+                }
+                _ => key.push('\\'),
+            }
+        } else {
+            key.push(chars.next().unwrap());
+        }
+    }
+
+    // Error handling
+    if let Some('}') = chars.peek() {
+        chars.next();
+    } else if apply_template {
+        return Err(StringTemplaterError::MissingCurvyBracket(format!(
+            "Missing three curvy bracket `}}` around `{}`.",
+            key
+        )));
+    } else {
+        return Err(StringTemplaterError::MissingCurvyBracket(format!(
+            "Missing two curvy bracket `}}` around `{}`.",
+            key
+        )));
+    }
+    if let Some('}') = chars.peek() {
+        chars.next();
+    } else if apply_template {
+        return Err(StringTemplaterError::MissingCurvyBracket(format!(
+            "Missing two curvy bracket `}}` around `{}`.",
+            key
+        )));
+    } else {
+        return Err(StringTemplaterError::MissingCurvyBracket(format!(
+            "Missing one curvy bracket `}}` around `{}`.",
+            key
+        )));
+    }
+    if apply_template {
+        if let Some('}') = chars.peek() {
+            chars.next();
+        } else {
+            return Err(StringTemplaterError::MissingCurvyBracket(format!(
+                "Missing one curvy bracket `}}` around `{}`.",
+                key
+            )));
+        }
+    }
+
+    let kind = match (apply_template, pointer, inject) {
+        (true, true, _) => PlaceholderKind::PointerInclude,
+        (true, _, true) => PlaceholderKind::Inject,
+        (true, _, _) => PlaceholderKind::Include,
+        (false, true, _) => PlaceholderKind::Pointer,
+        (false, _, _) => PlaceholderKind::Data,
+    };
+    Ok(Placeholder { kind, key })
+}
@@ -0,0 +1,65 @@
+#![cfg(all(feature = "codegen", feature = "yew-i18n", feature = "ssr"))]
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use yew::prelude::*;
+use yew_translator::{generate_keys_module, use_translation, I18nProvider};
+
+mod keys {
+    include!("fixtures/keys.rs");
+}
+
+fn translations() -> HashMap<String, String> {
+    HashMap::from([
+        ("menu.home".to_string(), "Home".to_string()),
+        ("hello".to_string(), "Hello {{name}}.".to_string()),
+        ("hard_hello".to_string(), "Hmm... {{{hello}}}".to_string()),
+    ])
+}
+
+#[test]
+fn test_generated_keys_module() {
+    assert_eq!(
+        generate_keys_module(&translations()).unwrap(),
+        include_str!("fixtures/keys.rs")
+    );
+}
+
+#[tokio::test]
+async fn test_generated_accessors() {
+    #[function_component]
+    fn Greeting() -> Html {
+        let i18n = use_translation();
+        let name = String::from("John");
+        html! {
+            <>
+                <p>{ keys::menu_home(&i18n) }</p>
+                <p>{ keys::hello(&i18n, keys::HelloArgs { name: name.clone() }) }</p>
+                <p>{ keys::hard_hello(&i18n, keys::HardHelloArgs { name }) }</p>
+            </>
+        }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        let translations = Rc::new(HashMap::from([(
+            "en".to_string(),
+            serde_json::to_value(translations()).unwrap(),
+        )]));
+        html! {
+            <I18nProvider {translations}>
+                <Greeting />
+            </I18nProvider>
+        }
+    }
+
+    let rendered = yew::ServerRenderer::<App>::new()
+        .hydratable(false)
+        .render()
+        .await;
+    assert_eq!(
+        rendered,
+        "<p>Home</p><p>Hello John.</p><p>Hmm... Hello John.</p>"
+    );
+}
@@ -0,0 +1,39 @@
+#[doc = "`hard_hello`: Hmm... {{{hello}}}"]
+pub fn hard_hello(handler: &::yew_translator::I18nHandler, args: HardHelloArgs) -> ::std::string::String {
+    handler.tth(
+        "hard_hello",
+        &::std::collections::HashMap::from([
+            (::std::string::String::from("name"), args.name),
+        ]),
+    )
+}
+
+#[doc = "Data of the template `hard_hello`."]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HardHelloArgs {
+    #[doc = "`name`"]
+    pub name: ::std::string::String,
+}
+
+#[doc = "`hello`: Hello {{name}}."]
+pub fn hello(handler: &::yew_translator::I18nHandler, args: HelloArgs) -> ::std::string::String {
+    handler.tth(
+        "hello",
+        &::std::collections::HashMap::from([
+            (::std::string::String::from("name"), args.name),
+        ]),
+    )
+}
+
+#[doc = "Data of the template `hello`."]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HelloArgs {
+    #[doc = "`name`"]
+    pub name: ::std::string::String,
+}
+
+#[doc = "`menu.home`: Home"]
+pub fn menu_home(handler: &::yew_translator::I18nHandler) -> ::std::string::String {
+    handler.t("menu.home")
+}
+
@@ -0,0 +1,155 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+
+use yew_translator::{encode_json_to_hashmap, required_fields, TranslationLookup};
+
+/// The namespace of the catalogs stored directly in the locales directory.
+const DEFAULT_NAMESPACE: &str = "common";
+
+/// The flattened translations of the source language, read from the locales directory.
+pub(crate) struct Catalog {
+    /// The file the translations were read from.
+    pub(crate) path: PathBuf,
+    /// The flattened translations.
+    pub(crate) translations: HashMap<String, String>,
+    /// The namespace of the catalog, if not the default one.
+    pub(crate) namespace: Option<String>,
+    /// The catalog of the default namespace, in which the includes of another namespace fall back.
+    pub(crate) common: Option<Box<Catalog>>,
+}
+
+/// The directory of the catalogs, `locales` (relative to the crate) unless `YEW_TRANSLATOR_LOCALES` is set.
+fn locales_dir() -> PathBuf {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let locales = std::env::var("YEW_TRANSLATOR_LOCALES").unwrap_or_else(|_| "locales".to_string());
+    PathBuf::from(manifest_dir).join(locales)
+}
+
+/// The language the keys are checked against, `en` unless `YEW_TRANSLATOR_SOURCE_LANGUAGE` is set.
+fn source_language() -> String {
+    std::env::var("YEW_TRANSLATOR_SOURCE_LANGUAGE").unwrap_or_else(|_| "en".to_string())
+}
+
+/// Split a key into its namespace (if not the default one) and its key inside the namespace.
+pub(crate) fn split_namespace(key: &str) -> (Option<&str>, &str) {
+    match key.split_once(':') {
+        Some((DEFAULT_NAMESPACE, key)) => (None, key),
+        Some((namespace, key)) => (Some(namespace), key),
+        None => (None, key),
+    }
+}
+
+impl Catalog {
+    /// Load the catalog of the source language, `<locales>/<language>.json` or `<locales>/<namespace>/<language>.json`.
+    /// The catalog of the default namespace is loaded as well (if it exists) for the includes of another namespace.
+    pub(crate) fn load(namespace: Option<&str>) -> Result<Catalog, String> {
+        let path = Catalog::path(namespace);
+        let common = match namespace {
+            Some(_) if Catalog::path(None).exists() => Some(Box::new(Catalog::load(None)?)),
+            _ => None,
+        };
+        let content = std::fs::read_to_string(&path)
+            .map_err(|err| format!("Can't read the catalog `{}`: {}", path.display(), err))?;
+        let json: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|err| format!("Can't parse the catalog `{}`: {}", path.display(), err))?;
+        Ok(Catalog {
+            path,
+            translations: encode_json_to_hashmap(&json),
+            namespace: namespace.map(str::to_string),
+            common,
+        })
+    }
+
+    /// The path of the catalog of a namespace in the source language.
+    fn path(namespace: Option<&str>) -> PathBuf {
+        let mut path = locales_dir();
+        if let Some(namespace) = namespace {
+            path.push(namespace);
+        }
+        path.push(format!("{}.json", source_language()));
+        path
+    }
+
+    /// The files the translations were read from.
+    pub(crate) fn paths(&self) -> impl Iterator<Item = &PathBuf> {
+        std::iter::once(&self.path).chain(self.common.iter().map(|common| &common.path))
+    }
+
+    /// Collect the data fields required by the template of `key`, following its `{{{…}}}` includes.
+    pub(crate) fn required_fields(&self, key: &str) -> Result<BTreeSet<String>, String> {
+        required_fields(self, key)
+            .map_err(|err| format!("The template of `{}` is invalid: {}", key, err))
+    }
+}
+
+impl TranslationLookup for Catalog {
+    /// Find an included key like the provider: prefixed by its namespace (`namespace:key`), or looked for in
+    /// the namespace of the catalog first, then in the default one.
+    fn lookup(&self, key: &str) -> Option<&String> {
+        match key.split_once(':') {
+            Some((DEFAULT_NAMESPACE, key)) => {
+                self.common.as_deref().unwrap_or(self).translations.get(key)
+            }
+            Some((namespace, key)) if self.namespace.as_deref() == Some(namespace) => {
+                self.translations.get(key)
+            }
+            Some(_) => None,
+            None => self.translations.get(key).or_else(|| {
+                self.common
+                    .as_ref()
+                    .and_then(|common| common.translations.get(key))
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeSet, HashMap};
+    use std::path::PathBuf;
+
+    use super::Catalog;
+
+    #[test]
+    fn test_required_fields() {
+        let catalog = Catalog {
+            path: PathBuf::from("en.json"),
+            translations: HashMap::from([
+                ("hello".to_string(), "Hello {{name}}.".to_string()),
+                (
+                    "welcome".to_string(),
+                    "{{{hello}}} You have {{count}} {{*unit}}.".to_string(),
+                ),
+            ]),
+            namespace: None,
+            common: None,
+        };
+        assert_eq!(
+            catalog.required_fields("welcome").unwrap(),
+            BTreeSet::from(["count".to_string(), "name".to_string(), "unit".to_string()])
+        );
+        assert!(catalog.required_fields("typo").is_err());
+
+        let checkout = Catalog {
+            path: PathBuf::from("checkout/en.json"),
+            translations: HashMap::from([
+                ("confirm".to_string(), "{{{ok}}} {{{hello}}}".to_string()),
+                ("ok".to_string(), "Pay {{amount}}".to_string()),
+                ("back".to_string(), "{{{common:ok}}}".to_string()),
+            ]),
+            namespace: Some("checkout".to_string()),
+            common: Some(Box::new(Catalog {
+                translations: HashMap::from([
+                    ("ok".to_string(), "OK".to_string()),
+                    ("hello".to_string(), "Hello {{name}}.".to_string()),
+                ]),
+                ..catalog
+            })),
+        };
+        assert_eq!(
+            checkout.required_fields("confirm").unwrap(),
+            BTreeSet::from(["amount".to_string(), "name".to_string()])
+        );
+        assert!(checkout.required_fields("back").unwrap().is_empty());
+    }
+}
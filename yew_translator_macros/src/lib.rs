@@ -0,0 +1,183 @@
+mod catalog;
+
+use catalog::{split_namespace, Catalog};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Expr, LitStr, Token};
+
+/// The arguments of the translation macros: `handler, "key"` followed by the data, if any.
+struct TranslationCall {
+    handler: Expr,
+    key: LitStr,
+    data: Vec<Expr>,
+}
+
+impl Parse for TranslationCall {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let handler = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let key = input.parse()?;
+        let data = if input.is_empty() {
+            Vec::new()
+        } else {
+            input.parse::<Token![,]>()?;
+            Punctuated::<Expr, Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect()
+        };
+        Ok(TranslationCall { handler, key, data })
+    }
+}
+
+/// Load the catalog of the key, failing if the key doesn't exist in it.
+fn checked_catalog(key: &LitStr) -> syn::Result<(Catalog, String)> {
+    let value = key.value();
+    let (namespace, relative_key) = split_namespace(&value);
+    let catalog =
+        Catalog::load(namespace).map_err(|message| syn::Error::new(key.span(), message))?;
+    if !catalog.translations.contains_key(relative_key) {
+        return Err(syn::Error::new(
+            key.span(),
+            format!(
+                "The key `{}` does not exist in `{}`",
+                value,
+                catalog.path.display()
+            ),
+        ));
+    }
+    Ok((catalog, relative_key.to_string()))
+}
+
+/// Expand to the compile errors, wrapped in a block to stay usable as an expression.
+fn compile_error(err: syn::Error) -> TokenStream {
+    let errors = err.to_compile_error();
+    quote!({ #errors }).into()
+}
+
+/// Track the catalogs so the crate is rebuilt whenever they change.
+fn track(catalog: &Catalog) -> proc_macro2::TokenStream {
+    let paths = catalog.paths().map(|path| path.display().to_string());
+    quote!(
+        #(const _: &[u8] = include_bytes!(#paths);)*
+    )
+}
+
+/// Translate a key, checking at compile time that it exists in the source catalog.
+///
+/// `t!(handler, "menu.home")` expands to `handler.t("menu.home")`.
+#[proc_macro]
+pub fn t(input: TokenStream) -> TokenStream {
+    let TranslationCall { handler, key, data } = parse_macro_input!(input as TranslationCall);
+    if let Some(data) = data.first() {
+        return compile_error(syn::Error::new_spanned(
+            data,
+            "`t!` doesn't take any data, use `tt!` instead",
+        ));
+    }
+    match checked_catalog(&key) {
+        Ok((catalog, _)) => {
+            let track = track(&catalog);
+            quote!({
+                #track
+                (#handler).t(#key)
+            })
+            .into()
+        }
+        Err(err) => compile_error(err),
+    }
+}
+
+/// Translate a template, checking at compile time that its key exists in the source catalog.
+///
+/// `tt!(handler, "hello", data)` expands to `handler.tt("hello", &data)`, while
+/// `tt!(handler, "hello", name = "John")` also checks that every placeholder of the template
+/// (and of its `{{{…}}}` includes) is given, and nothing more.
+#[proc_macro]
+pub fn tt(input: TokenStream) -> TokenStream {
+    let TranslationCall { handler, key, data } = parse_macro_input!(input as TranslationCall);
+    let (catalog, relative_key) = match checked_catalog(&key) {
+        Ok(checked) => checked,
+        Err(err) => return compile_error(err),
+    };
+    let track = track(&catalog);
+
+    let named = data.iter().all(|expr| matches!(expr, Expr::Assign(_)));
+    if !named || data.is_empty() {
+        return match data.as_slice() {
+            [data] => quote!({
+                #track
+                (#handler).tt(#key, &(#data))
+            })
+            .into(),
+            _ => compile_error(syn::Error::new(
+                key.span(),
+                "`tt!` takes either one data expression or named fields (`name = value`)",
+            )),
+        };
+    }
+
+    let required = match catalog.required_fields(&relative_key) {
+        Ok(required) => required,
+        Err(message) => return compile_error(syn::Error::new(key.span(), message)),
+    };
+    let mut errors: Option<syn::Error> = None;
+    let mut push_error = |err: syn::Error| match errors.as_mut() {
+        Some(errors) => errors.combine(err),
+        None => errors = Some(err),
+    };
+    let mut fields = Vec::new();
+    let mut given = Vec::new();
+    for expr in &data {
+        let Expr::Assign(assign) = expr else {
+            continue;
+        };
+        let name = match &*assign.left {
+            Expr::Path(path) if path.path.get_ident().is_some() => {
+                path.path.get_ident().unwrap().to_string()
+            }
+            left => {
+                push_error(syn::Error::new_spanned(left, "Expected a field name"));
+                continue;
+            }
+        };
+        if !required.contains(&name) {
+            push_error(syn::Error::new_spanned(
+                &assign.left,
+                format!(
+                    "The template of `{}` has no placeholder `{}`",
+                    key.value(),
+                    name
+                ),
+            ));
+        }
+        let value = &assign.right;
+        fields.push(quote!((
+            ::std::string::String::from(#name),
+            ::std::string::ToString::to_string(&(#value)),
+        )));
+        given.push(name);
+    }
+    for field in required.iter().filter(|field| !given.contains(field)) {
+        push_error(syn::Error::new(
+            key.span(),
+            format!(
+                "The template of `{}` requires the placeholder `{}`",
+                key.value(),
+                field
+            ),
+        ));
+    }
+    if let Some(errors) = errors {
+        return compile_error(errors);
+    }
+    quote!({
+        #track
+        (#handler).tth(
+            #key,
+            &::std::collections::HashMap::from([#(#fields),*]),
+        )
+    })
+    .into()
+}
@@ -0,0 +1,14 @@
+#[test]
+fn test_macros() {
+    // The cases are built from their own crate, so the catalogs are given by their absolute path.
+    std::env::set_var(
+        "YEW_TRANSLATOR_LOCALES",
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/locales"),
+    );
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/pass.rs");
+    cases.compile_fail("tests/ui/unknown_key.rs");
+    cases.compile_fail("tests/ui/namespaced_key.rs");
+    cases.compile_fail("tests/ui/missing_field.rs");
+    cases.compile_fail("tests/ui/extra_field.rs");
+}
@@ -0,0 +1,8 @@
+use yew_translator_macros::tt;
+
+mod handler;
+
+fn main() {
+    let handler = handler::Handler;
+    tt!(handler, "hello", name = "John", age = 42);
+}
@@ -0,0 +1,9 @@
+use yew_translator_macros::t;
+
+mod handler;
+
+fn main() {
+    let handler = handler::Handler;
+    t!(handler, "checkout:menu.home");
+    t!(handler, "billing:title");
+}
@@ -0,0 +1,20 @@
+use yew_translator_macros::{t, tt};
+
+mod handler;
+
+fn main() {
+    let handler = handler::Handler;
+    assert_eq!(t!(handler, "menu.home"), "menu.home");
+    assert_eq!(t!(handler, "common:ok"), "common:ok");
+    assert_eq!(t!(handler, "checkout:title"), "checkout:title");
+    assert_eq!(tt!(handler, "hello", name = "John"), "hello(name=John)");
+    assert_eq!(tt!(handler, "hello", ("John",)), "hello");
+    assert_eq!(
+        tt!(handler, "checkout:confirm", name = "John"),
+        "checkout:confirm(name=John)"
+    );
+    assert_eq!(
+        tt!(handler, "checkout:total", amount = 3),
+        "checkout:total(amount=3)"
+    );
+}
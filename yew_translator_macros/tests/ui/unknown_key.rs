@@ -0,0 +1,8 @@
+use yew_translator_macros::t;
+
+mod handler;
+
+fn main() {
+    let handler = handler::Handler;
+    t!(handler, "menu.typo");
+}